@@ -1,9 +1,11 @@
+use crate::mock_gateway::MockGateway;
 use crate::mock_http::MockHttp;
 use crate::mock_ns::MockNS;
 use crate::mock_sb::MockSB;
 use env_logger::Env;
 use log::info;
 
+mod mock_gateway;
 mod mock_http;
 mod mock_ns;
 mod mock_sb;
@@ -15,6 +17,7 @@ async fn main() {
     tokio::join!(
         MockHttp::mock_passport(),
         MockNS::listen(),
-        MockSB::listen()
+        MockSB::listen(),
+        MockGateway::listen()
     );
 }
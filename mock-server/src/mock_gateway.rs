@@ -0,0 +1,167 @@
+use axum::Router;
+use axum::body::Bytes;
+use axum::extract::{Query, State};
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+use axum::routing::post;
+use log::{error, trace};
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::time::{Duration, Instant};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpStream;
+use tokio::sync::Mutex;
+
+struct Session {
+    socket: TcpStream,
+    last_request: Instant,
+}
+
+#[derive(Clone, Default)]
+struct Sessions {
+    sessions: Arc<Mutex<HashMap<String, Arc<Mutex<Session>>>>>,
+    last_id: Arc<AtomicU32>,
+}
+
+impl Sessions {
+    /// Closes sessions whose client stopped polling, like the real gateway does.
+    async fn expire(self) {
+        loop {
+            tokio::time::sleep(Duration::from_secs(1)).await;
+
+            let mut sessions = self.sessions.lock().await;
+            let mut expired = Vec::new();
+            for (session_id, session) in sessions.iter() {
+                if let Ok(session) = session.try_lock()
+                    && session.last_request.elapsed() > Duration::from_secs(10)
+                {
+                    expired.push(session_id.clone());
+                }
+            }
+
+            for session_id in expired {
+                trace!("Gateway {session_id} expired");
+                sessions.remove(&session_id);
+            }
+        }
+    }
+}
+
+/// Stand-in for `gateway.dll`, relaying HTTP bodies to the mock NS and SB.
+pub struct MockGateway;
+
+impl MockGateway {
+    pub async fn listen() {
+        let sessions = Sessions::default();
+        tokio::spawn(sessions.clone().expire());
+
+        let app = Router::new()
+            .route("/gateway/gateway.dll", post(Self::gateway))
+            .with_state(sessions);
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:3001")
+            .await
+            .expect("Could not bind mock HTTP gateway");
+
+        if let Err(error) = axum::serve(listener, app).await {
+            error!("{error}");
+        }
+    }
+
+    async fn gateway(
+        State(sessions): State<Sessions>,
+        Query(params): Query<HashMap<String, String>>,
+        body: Bytes,
+    ) -> impl IntoResponse {
+        let session_id = if params.get("Action").map(String::as_str) == Some("open") {
+            let Some(ip) = params.get("IP") else {
+                return Err(StatusCode::BAD_REQUEST);
+            };
+
+            let address = if ip.contains(':') {
+                ip.to_string()
+            } else {
+                format!("{ip}:1863")
+            };
+
+            let socket = TcpStream::connect(address)
+                .await
+                .or(Err(StatusCode::BAD_GATEWAY))?;
+
+            let session_id = format!("{}.1", sessions.last_id.fetch_add(1, Ordering::SeqCst));
+            let session = Session {
+                socket,
+                last_request: Instant::now(),
+            };
+
+            sessions
+                .sessions
+                .lock()
+                .await
+                .insert(session_id.clone(), Arc::new(Mutex::new(session)));
+
+            session_id
+        } else {
+            params
+                .get("SessionID")
+                .cloned()
+                .ok_or(StatusCode::BAD_REQUEST)?
+        };
+
+        let session = sessions
+            .sessions
+            .lock()
+            .await
+            .get(&session_id)
+            .cloned()
+            .ok_or(StatusCode::BAD_REQUEST)?;
+
+        let mut session = session.lock().await;
+        session.last_request = Instant::now();
+
+        let socket = &mut session.socket;
+        let mut close = String::from_utf8_lossy(&body)
+            .lines()
+            .any(|line| line == "OUT");
+
+        if !body.is_empty() {
+            trace!("Gateway {session_id}: {}", String::from_utf8_lossy(&body));
+            socket
+                .write_all(&body)
+                .await
+                .or(Err(StatusCode::BAD_GATEWAY))?;
+        }
+
+        // Return whatever the server replies with shortly after
+        let mut reply = Vec::new();
+        let mut buf = vec![0; 1664];
+        while let Ok(received) =
+            tokio::time::timeout(Duration::from_millis(50), socket.read(&mut buf)).await
+        {
+            match received {
+                Ok(received) if received > 0 => reply.extend_from_slice(&buf[..received]),
+                _ => {
+                    close = true;
+                    break;
+                }
+            }
+        }
+
+        if close {
+            sessions.sessions.lock().await.remove(&session_id);
+        }
+
+        let session = if close { "close" } else { "active" };
+        Ok((
+            [
+                ("Content-Type", "application/x-msn-messenger".to_string()),
+                (
+                    "X-MSN-Messenger",
+                    format!("SessionID={session_id}; GW-IP=127.0.0.1; Session={session}"),
+                ),
+            ],
+            reply,
+        ))
+    }
+}
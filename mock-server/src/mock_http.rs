@@ -1,4 +1,5 @@
 use axum::Router;
+use axum::http::StatusCode;
 use axum::response::IntoResponse;
 use axum::routing::{get, post};
 use hyper::Request;
@@ -15,6 +16,7 @@ impl MockHttp {
         let app = Router::new()
             .route("/rdr/pprdr.asp", get(Self::nexus))
             .route("/login.srf", get(Self::login_srf))
+            .route("/RST.srf", post(Self::rst))
             .route("/Config/MsgrConfig.asmx", post(Self::config));
 
         let listener = tokio::net::TcpListener::bind("127.0.0.1:3000")
@@ -63,6 +65,57 @@ impl MockHttp {
         )]
     }
 
+    async fn rst(body: String) -> impl IntoResponse {
+        if !body.contains("<wsse:Username>testing@example.com</wsse:Username>")
+            || !body.contains("URI=\"?ct=1,rver=1,wp=FS_40SEC_0_COMPACT,lc=1,id=1\"")
+        {
+            return (StatusCode::INTERNAL_SERVER_ERROR, String::new());
+        }
+
+        (
+            StatusCode::OK,
+            "<?xml version=\"1.0\" encoding=\"utf-8\" ?>
+            <S:Envelope xmlns:S=\"http://schemas.xmlsoap.org/soap/envelope/\">
+                <S:Body>
+                    <wst:RequestSecurityTokenResponseCollection \
+                        xmlns:wst=\"http://schemas.xmlsoap.org/ws/2004/04/trust\" \
+                        xmlns:wsse=\"http://schemas.xmlsoap.org/ws/2003/06/secext\" \
+                        xmlns:wsp=\"http://schemas.xmlsoap.org/ws/2002/12/policy\" \
+                        xmlns:wsa=\"http://schemas.xmlsoap.org/ws/2004/03/addressing\" \
+                        xmlns:wsu=\"http://docs.oasis-open.org/wss/2004/01/oasis-200401-wss-wssecurity-utility-1.0.xsd\">
+                        <wst:RequestSecurityTokenResponse>
+                            <wst:TokenType>urn:passport:legacy</wst:TokenType>
+                            <wsp:AppliesTo>
+                                <wsa:EndpointReference>
+                                    <wsa:Address>http://Passport.NET/tb</wsa:Address>
+                                </wsa:EndpointReference>
+                            </wsp:AppliesTo>
+                            <wst:RequestedSecurityToken>
+                                <EncryptedData Id=\"BinaryDAToken0\"></EncryptedData>
+                            </wst:RequestedSecurityToken>
+                        </wst:RequestSecurityTokenResponse>
+                        <wst:RequestSecurityTokenResponse>
+                            <wst:TokenType>urn:passport:compact</wst:TokenType>
+                            <wst:Lifetime>
+                                <wsu:Created>2006-04-11T17:42:20Z</wsu:Created>
+                                <wsu:Expires>2099-04-12T01:42:20Z</wsu:Expires>
+                            </wst:Lifetime>
+                            <wsp:AppliesTo>
+                                <wsa:EndpointReference>
+                                    <wsa:Address>messenger.msn.com</wsa:Address>
+                                </wsa:EndpointReference>
+                            </wsp:AppliesTo>
+                            <wst:RequestedSecurityToken>
+                                <wsse:BinarySecurityToken Id=\"PPToken1\">aaa123aaa123</wsse:BinarySecurityToken>
+                            </wst:RequestedSecurityToken>
+                        </wst:RequestSecurityTokenResponse>
+                    </wst:RequestSecurityTokenResponseCollection>
+                </S:Body>
+            </S:Envelope>"
+                .to_string(),
+        )
+    }
+
     async fn config() -> impl IntoResponse {
         // Response taken from Crosstalk
         "<?xml version=\"1.0\" encoding=\"utf-8\" ?>
@@ -47,6 +47,15 @@ impl MockNS {
                                 messages.push(new_bytes);
                             }
 
+                            "QRY" | "PAG" => {
+                                let length = args[3].parse::<usize>().unwrap();
+                                let length = message_lines[0].len() + length;
+
+                                let new_bytes = &messages_bytes[..length];
+                                messages_bytes = &messages_bytes[length..];
+                                messages.push(new_bytes);
+                            }
+
                             _ => {
                                 let new_bytes = &messages_bytes[..message_lines[0].len()];
                                 messages_bytes = &messages_bytes[message_lines[0].len()..];
@@ -69,8 +78,29 @@ impl MockNS {
                                 &["USR 3 TWN S ct=1,rver=1,wp=FS_40SEC_0_COMPACT,lc=1,id=1\r\n"]
                             }
 
-                            "USR 4 TWN S aaa123aaa123\r\n" => {
-                                &["USR 4 OK testing@example.com Testing 1 0\r\n"]
+                            "USR 4 TWN S aaa123aaa123\r\n" => &[
+                                "USR 4 OK testing@example.com Testing 1 0\r\n",
+                                "MSG Hotmail Hotmail 402\r\nMIME-Version: 1.0\r\nContent-Type: text/x-msmsgsprofile; charset=UTF-8\r\nLoginTime: 1110488522\r\nEmailEnabled: 1\r\nMemberIdHigh: 12345\r\nMemberIdLow: -123456789\r\nlang_preference: 1033\r\npreferredEmail: \r\ncountry: US\r\nPostalCode: \r\nGender: \r\nKid: 0\r\nAge: \r\nBDayPre: \r\nBirthday: \r\nWallet: \r\nFlags: 1027\r\nsid: 507\r\nkv: 7\r\nMSPAuth: 7RgobFm*Tb7nEdDemo$$\r\nClientIP: 127.0.0.1\r\nClientPort: 14599\r\nABCHMigrated: 1\r\n\r\n",
+                            ],
+
+                            // Stale cached ticket
+                            "USR 4 TWN S expired123\r\n" => &["911 4\r\n"],
+
+                            "SYN 7 0 0\r\n" => &[
+                                "SYN 7 0 0 2 1\r\n",
+                                "GTC A\r\n",
+                                "BLP AL\r\n",
+                                "PRP MFN Testing\r\n",
+                                "PRP PHH 555%20123\r\n",
+                                "LSG Mock%20Contacts 124153dc-a695-4f6c-93e8-8e07c9775251\r\n",
+                                "LST N=bob@passport.com F=Bob C=6bd736b8-dc18-44c6-ad61-8cd12d641e79 13 124153dc-a695-4f6c-93e8-8e07c9775251\r\n",
+                                "BPR PHM 555%20987\r\n",
+                                "BPR MOB Y\r\n",
+                                "LST N=fred@passport.com F=Fred 2\r\n",
+                            ],
+
+                            "GCF 8 Shields.xml\r\n" => {
+                                &["GCF 8 Shields.xml 33\r\n</shield><block></block></config>"]
                             }
 
                             "SYN 5 0 0\r\n" => &[
@@ -78,9 +108,13 @@ impl MockNS {
                                 "GTC A\r\n",
                                 "BLP AL\r\n",
                                 "PRP MFN Testing\r\n",
+                                "PRP PHH 555%20123\r\n",
                                 "LSG Mock%20Contacts 124153dc-a695-4f6c-93e8-8e07c9775251\r\n",
                                 "LST N=bob@passport.com F=Bob C=6bd736b8-dc18-44c6-ad61-8cd12d641e79 13 124153dc-a695-4f6c-93e8-8e07c9775251\r\n",
+                                "BPR PHM 555%20987\r\n",
+                                "BPR MOB Y\r\n",
                                 "LST N=fred@passport.com F=Fred 2\r\n",
+                                "MSG Hotmail Hotmail 221\r\nMIME-Version: 1.0\r\nContent-Type: text/x-msmsgsinitialemailnotification; charset=UTF-8\r\n\r\nInbox-Unread: 2\r\nFolders-Unread: 1\r\nInbox-URL: /cgi-bin/HoTMaiL\r\nFolders-URL: /cgi-bin/folders\r\nPost-URL: http://www.hotmail.com\r\n\r\n",
                             ],
 
                             "GCF 6 Shields.xml\r\n" => {
@@ -91,7 +125,9 @@ impl MockNS {
                                 "CHG 7 NLN 1073741824\r\n",
                                 "ILN 7 NLN bob@passport.com Bob 1073741824 %3Cmsnobj%20Creator%3D%22bob%40passport.com%22%20Size%3D%2222731%22%20Type%3D%223%22%20Location%3D%22TFRDDF.dat%22%20Friendly%3D%22AAA%3D%22%20SHA1D%3D%22G8fPpR6aONX286a8C2cFmeVbPsA%3D%22%20SHA1C%3D%22GBEWvLqBa1B6mBfFDavq%2BU0FRmk%3D%22%2F%3E\r\n",
                                 "NLN NLN bob@passport.com Bob 1073741824 %3Cmsnobj%20Creator%3D%22bob%40passport.com%22%20Size%3D%2222731%22%20Type%3D%223%22%20Location%3D%22TFRDDF.dat%22%20Friendly%3D%22AAA%3D%22%20SHA1D%3D%22G8fPpR6aONX286a8C2cFmeVbPsA%3D%22%20SHA1C%3D%22GBEWvLqBa1B6mBfFDavq%2BU0FRmk%3D%22%2F%3E\r\n",
-                                "UBX bob@passport.com 70\r\n<Data><PSM>my msn all ducked</PSM><CurrentMedia></CurrentMedia></Data>",
+                                "UBX bob@passport.com 118\r\n<Data><PSM>my msn all ducked</PSM><CurrentMedia>\\0Music\\01\\0{0} - {1}\\0Rock &amp; Roll\\0Band\\0\\0</CurrentMedia></Data>",
+                                "MSG Hotmail Hotmail 348\r\nMIME-Version: 1.0\r\nContent-Type: text/x-msmsgsemailnotification; charset=UTF-8\r\n\r\nFrom: Hotmail Staff\r\nMessage-URL: /cgi-bin/getmsg?msg=MSG1050451140.21&start=2310&len=2059&curmbox=ACTIVE\r\nPost-URL: https://loginnet.passport.com/ppsecure/md5auth.srf?lc=1033\r\nSubject: Welcome to Hotmail\r\nDest-Folder: ACTIVE\r\nFrom-Addr: staff@hotmail.com\r\nid: 2\r\n\r\n",
+                                "MSG Hotmail Hotmail 147\r\nMIME-Version: 1.0\r\nContent-Type: text/x-msmsgsactivemailnotification; charset=UTF-8\r\n\r\nSrc-Folder: ACTIVE\r\nDest-Folder: trAsH\r\nMessage-Delta: 1\r\n\r\n",
                             ],
 
                             "UUX 8 43\r\n<Data><PSM>test</PSM><CurrentMedia/></Data>" => &[
@@ -99,6 +135,37 @@ impl MockNS {
                                 "MSG Hotmail Hotmail 88\r\nMIME-Version: 1.0\r\nContent-Type: application/x-msmsgssystemmessage\r\n\r\nType: 1\r\nArg1: 5\r\n",
                             ],
 
+                            // Followed by live changes to contacts' properties
+                            "PRP 7 PHH 555%20555\r\n" => &[
+                                "PRP 7 PHH 555%20555\r\n",
+                                "BPR fred@passport.com PHW 555%20111\r\n",
+                                "BPR fred@passport.com PHM\r\n",
+                            ],
+
+                            "PAG 8 bob@passport.com 64\r\n<TEXT xml:space=\"preserve\" enc=\"utf-8\">call me &amp; text</TEXT>" => {
+                                &["PAG 8\r\n"]
+                            }
+
+                            // Challenge, only answered if the hash is correct
+                            "CHG 7 BSY 1073741824\r\n" => {
+                                &["CHG 7 BSY 1073741824\r\n", "CHL 0 22210219642164014968\r\n"]
+                            }
+
+                            "QRY 8 PROD0090YUAUV{2B 32\r\n85ecb0db8f32113df79ce0892b9a102c" => {
+                                &["QRY 8\r\n"]
+                            }
+
+                            "URL 7 INBOX\r\n" => &[
+                                "URL 7 /cgi-bin/HoTMaiL https://loginnet.passport.com/ppsecure/md5auth.srf?lc=1033 2\r\n",
+                            ],
+
+                            "BLP 7 BL\r\n" => &[
+                                "BLP 7 BL\r\n",
+                                "NOT 315\r\n<NOTIFICATION ver=\"2\" id=\"1342902633\" siteid=\"199999999\" siteurl=\"http://alerts.msn.com\"><TO pid=\"0x00060000:0x81ee5a43\" name=\"testing@example.com\"/><MSG pri=\"\" id=\"1342902633\"><SUBSCR url=\"s.htm\"/><ACTION url=\"a.htm\"/><BODY lang=\"3076\" icon=\"\"><TEXT>Your favorite band is on tour</TEXT></BODY></MSG></NOTIFICATION>",
+                                "MSG Hotmail Hotmail 79\r\nMIME-Version: 1.0\r\nContent-Type: application/x-msmsgssystemmessage\r\n\r\nType: 2\r\n",
+                                "MSG Hotmail Hotmail 101\r\nMIME-Version: 1.0\r\nContent-Type: application/x-msmsgssystemmessage\r\n\r\nType: 5\r\nArg1: 10\r\nArg2: test\r\n",
+                            ],
+
                             "PNG\r\n" => &["QNG 60\r\n"],
                             "ADC 7 FL N=bob@passport.com F=Bob\r\n" => &[
                                 "ADC 7 FL N=bob@passport.com F=Bob C=6bd736b8-dc18-44c6-ad61-8cd12d641e79\r\n",
@@ -109,13 +176,77 @@ impl MockNS {
                                 &["ADC 8 AL N=fred@passport.com\r\n"]
                             }
 
+                            "REM 7 BL bob@passport.com\r\n" => &["REM 7 BL bob@passport.com\r\n"],
+                            "REM 10 AL fred@passport.com\r\n" => {
+                                &["REM 10 AL fred@passport.com\r\n"]
+                            }
+                            "ADC 11 BL N=fred@passport.com\r\n" => &["603 11\r\n"],
+                            "ADC 12 AL N=fred@passport.com\r\n" => {
+                                &["ADC 12 AL N=fred@passport.com\r\n"]
+                            }
+                            "ADC 8 AL N=bob@passport.com\r\n" => {
+                                &["ADC 8 AL N=bob@passport.com\r\n"]
+                            }
+                            "BLP 9 BL\r\n" => &["BLP 9 BL\r\n"],
+
+                            "CHG 7 NLN 1073774624\r\n" => &["CHG 7 NLN 1073774624\r\n"],
+
+                            "UUX 8 108\r\n<Data><PSM>listening</PSM><CurrentMedia>\\0Music\\01\\0{0} - {1}\\0Rock &amp; Roll\\0Band\\0</CurrentMedia></Data>" => {
+                                &["UUX 8 0\r\n"]
+                            }
+
+                            // Auto-away
+                            "CHG 8 IDL 1073741824\r\n" => &["CHG 8 IDL 1073741824\r\n"],
+                            "CHG 9 NLN 1073741824\r\n" => &["201 9\r\n"],
+                            "UUX 9 42\r\n<Data><PSM>brb</PSM><CurrentMedia/></Data>" => {
+                                &["UUX 9 0\r\n"]
+                            }
+                            "CHG 10 AWY 1073741824\r\n" => &["CHG 10 AWY 1073741824\r\n"],
+                            "CHG 11 NLN 1073741824\r\n" => &["CHG 11 NLN 1073741824\r\n"],
+                            "UUX 12 34\r\n<Data><PSM/><CurrentMedia/></Data>" => &["UUX 12 0\r\n"],
+
+                            // Contact requests
+                            "CHG 7 HDN 1073741824\r\n" => &[
+                                "CHG 7 HDN 1073741824\r\n",
+                                "ADC 0 RL N=carol@passport.com F=Carol\r\n",
+                                "ADC 0 RL N=dave@passport.com F=Dave\r\n",
+                            ],
+
+                            "ADC 8 AL N=carol@passport.com\r\n" => {
+                                &["ADC 8 AL N=carol@passport.com\r\n"]
+                            }
+
+                            "ADC 9 FL N=carol@passport.com F=Carol\r\n" => &[
+                                "ADC 9 FL N=carol@passport.com F=Carol C=0e4f4a2c-2a3b-4c5d-8e9f-a0b1c2d3e4f5\r\n",
+                            ],
+
+                            "ADC 10 FL C=0e4f4a2c-2a3b-4c5d-8e9f-a0b1c2d3e4f5 124153dc-a695-4f6c-93e8-8e07c9775251\r\n" => {
+                                &[
+                                    "ADC 10 FL C=0e4f4a2c-2a3b-4c5d-8e9f-a0b1c2d3e4f5 124153dc-a695-4f6c-93e8-8e07c9775251\r\n",
+                                ]
+                            }
+
+                            "REM 11 PL carol@passport.com\r\n" => {
+                                &["REM 11 PL carol@passport.com\r\n"]
+                            }
+
+                            "ADC 12 BL N=dave@passport.com\r\n" => {
+                                &["ADC 12 BL N=dave@passport.com\r\n"]
+                            }
+
+                            "REM 13 PL dave@passport.com\r\n" => {
+                                &["REM 13 PL dave@passport.com\r\n"]
+                            }
+
                             "XFR 7 SB\r\n" => &["XFR 7 SB 127.0.0.1:1864 CKI 123456\r\n"],
+                            "XFR 8 SB\r\n" => &["XFR 8 SB 127.0.0.1:1864 CKI 123456\r\n"],
 
-                            "GTC 7 ReceiveRNG\r\n" => &[
-                                "GTC 7 ReceiveRNG\r\n",
+                            "GTC 7 N\r\n" => &[
+                                "GTC 7 N\r\n",
                                 "RNG 11752013 127.0.0.1:1864 CKI 123456 bob@passport.com Bob\r\n",
                             ],
 
+                            message if message.starts_with("QRY ") => &["540 8\r\n"],
                             _ => &[],
                         };
 
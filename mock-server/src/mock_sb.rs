@@ -16,7 +16,7 @@ impl MockSB {
                 let (mut socket, _) = client;
                 let (mut rd, mut wr) = socket.split();
 
-                let mut buf = vec![0; 1664];
+                let mut buf = vec![0; 4096];
                 while let Ok(received) = rd.read(&mut buf).await {
                     if received == 0 {
                         break;
@@ -59,22 +59,54 @@ impl MockSB {
                         let message = unsafe { str::from_utf8_unchecked(message) };
                         trace!("C: {message}");
 
+                        // Group chat invitations are sent concurrently, so their transaction IDs vary
+                        let args: Vec<&str> = message.split_ascii_whitespace().collect();
+                        let group_replies: Vec<String> = match args.as_slice() {
+                            ["CAL", tr_id, email]
+                                if email.eq_ignore_ascii_case("alice@passport.com") =>
+                            {
+                                vec![
+                                    format!("CAL {tr_id} RINGING 11752013\r\n"),
+                                    "JOI alice@passport.com Alice\r\n".to_string(),
+                                ]
+                            }
+
+                            ["CAL", tr_id, "erin@passport.com"] => vec![
+                                format!("CAL {tr_id} RINGING 11752013\r\n"),
+                                "JOI erin@passport.com Erin\r\n".to_string(),
+                            ],
+
+                            // Declines right away
+                            ["CAL", tr_id, "frank@passport.com"] => vec![
+                                format!("CAL {tr_id} RINGING 11752013\r\n"),
+                                "BYE frank@passport.com\r\n".to_string(),
+                            ],
+
+                            ["CAL", tr_id, "carol@passport.com"] => {
+                                vec![format!("217 {tr_id}\r\n")]
+                            }
+                            ["CAL", tr_id, "dave"] => vec![format!("208 {tr_id}\r\n")],
+                            _ => Vec::new(),
+                        };
+
                         let replies: &[&str] = match message {
                             "USR 1 testing@example.com 123456\r\n" => {
                                 &["USR 1 OK testing@example.com Testing\r\n"]
                             }
 
                             "ANS 1 testing@example.com 123456 11752013\r\n" => &[
-                                "IRO 1 1 1 bob@passport.com Bob\r\n",
+                                "IRO 1 1 1 bob@passport.com Bob%20Smith\r\n",
                                 "ANS 1 OK\r\n",
+                                "MSG bob@passport.com Bob 166\r\nMIME-Version: 1.0\r\nContent-Type: text/x-mms-emoticon\r\n\r\n(bob)\t<msnobj Creator=\"bob@passport.com\" Size=\"1\" Type=\"2\" Location=\"TFR1.dat\" Friendly=\"AAA=\" SHA1D=\"AAA=\"/>\t",
                                 "MSG bob@passport.com Bob 134\r\nMIME-Version: 1.0\r\nContent-Type: text/plain; charset=UTF-8\r\nX-MMS-IM-Format: FN=Microsoft%20Sans%20Serif; EF=; CO=ff; CS=0; PF=22\r\n\r\nh",
                                 "MSG bob@passport.com Bob 69\r\nMIME-Version: 1.0\r\nContent-Type: text/x-msnmsgr-datacast\r\n\r\nID: 1\r\n\r\n",
                                 "BYE bob@passport.com\r\n",
                             ],
 
-                            "CAL 2 bob@passport.com\r\n" => {
-                                &["CAL 2 RINGING 11752013\r\n", "JOI bob@passport.com\r\n"]
-                            }
+                            "CAL 2 bob@passport.com\r\n" => &[
+                                "CAL 2 RINGING 11752013\r\n",
+                                "JOI bob@passport.com Bob 1073741824\r\n",
+                            ],
 
                             "MSG 3 A 137\r\nMIME-Version: 1.0\r\nContent-Type: text/plain; charset=UTF-8\r\nX-MMS-IM-Format: FN=Microsoft%20Sans%20Serif; EF=; CO=ff0000; CS=1; PF=0\r\n\r\nh" => {
                                 &[
@@ -89,10 +121,77 @@ impl MockSB {
                                 &["ACK 4\r\n"]
                             }
 
+                            "CAL 4 bob@passport.com\r\n" => {
+                                &["CAL 4 RINGING 11752013\r\n", "JOI bob@passport.com\r\n"]
+                            }
+
+                            "MSG 5 A 137\r\nMIME-Version: 1.0\r\nContent-Type: text/plain; charset=UTF-8\r\nX-MMS-IM-Format: FN=Microsoft%20Sans%20Serif; EF=; CO=ff0000; CS=1; PF=0\r\n\r\nh" => {
+                                &["ACK 5\r\n"]
+                            }
+
+                            "MSG 3 U 92\r\nMIME-Version: 1.0\r\nContent-Type: text/x-msmsgscontrol\r\nTypingUser: testing@example.com\r\n\r\n\r\n" => {
+                                &[
+                                    "MSG bob@passport.com Bob 89\r\nMIME-Version: 1.0\r\nContent-Type: text/x-msmsgscontrol\r\nTypingUser: bob@passport.com\r\n\r\n\r\n",
+                                    "MSG bob@passport.com Bob 89\r\nMIME-Version: 1.0\r\nContent-Type: text/x-msmsgscontrol\r\nTypingUser: bob@passport.com\r\n\r\n\r\n",
+                                    "MSG bob@passport.com Bob 134\r\nMIME-Version: 1.0\r\nContent-Type: text/plain; charset=UTF-8\r\nX-MMS-IM-Format: FN=Microsoft%20Sans%20Serif; EF=; CO=ff; CS=0; PF=22\r\n\r\nh",
+                                ]
+                            }
+
+                            "MSG 5 U 92\r\nMIME-Version: 1.0\r\nContent-Type: text/x-msmsgscontrol\r\nTypingUser: testing@example.com\r\n\r\n\r\n" => {
+                                &[
+                                    "MSG bob@passport.com Bob 89\r\nMIME-Version: 1.0\r\nContent-Type: text/x-msmsgscontrol\r\nTypingUser: bob@passport.com\r\n\r\n\r\n",
+                                ]
+                            }
+
+                            "MSG 4 N 69\r\nMIME-Version: 1.0\r\nContent-Type: text/x-msnmsgr-datacast\r\n\r\nID: 1\r\n\r\n" => {
+                                &["NAK 4\r\n"]
+                            }
+
+                            "MSG 3 A 84\r\nMIME-Version: 1.0\r\nContent-Type: text/x-clientcaps\r\nClient-Name: msnp11-sdk\r\n\r\nhello" => {
+                                &[
+                                    "ACK 3\r\n",
+                                    "MSG bob@passport.com Bob 103\r\nMIME-Version: 1.0\r\nContent-Type: text/x-clientcaps\r\nClient-Name: Bob Messenger 1.0\r\nChat-Logging: Y\r\n\r\n",
+                                ]
+                            }
+
+                            "MSG 3 A 142\r\nMIME-Version: 1.0\r\nContent-Type: text/plain; charset=UTF-8\r\nX-MMS-IM-Format: FN=Segoe%20UI; EF=I; CO=ff0000; CS=0; PF=22; RL=1\r\n\r\nHello\r\nworld" => {
+                                &[
+                                    "ACK 3\r\n",
+                                    "MSG bob@passport.com Bob 142\r\nMIME-Version: 1.0\r\nContent-Type: text/plain; charset=UTF-8\r\nX-MMS-IM-Format: FN=Segoe%20UI; EF=I; CO=ff0000; CS=0; PF=22; RL=1\r\n\r\nHello\r\nworld",
+                                    "MSG bob@passport.com Bob 150\r\nMIME-Version: 1.0\r\nX-MMS-IM-Format: FN=MS%20Shell%20Dlg%202; EF=B; CO=808080; CS=80; PF=31\r\nContent-Type: text/plain; charset=UTF-8\r\n\r\nこんにちは",
+                                ]
+                            }
+
+                            "MSG 6 A 137\r\nMIME-Version: 1.0\r\nContent-Type: text/plain; charset=UTF-8\r\nX-MMS-IM-Format: FN=Microsoft%20Sans%20Serif; EF=; CO=ff0000; CS=1; PF=0\r\n\r\nh" => {
+                                &["ACK 6\r\n", "BYE erin@passport.com\r\n"]
+                            }
+
+                            message
+                                if message.starts_with("MSG 3 A ")
+                                    && message.contains("Chunks: 2\r\n") =>
+                            {
+                                &[
+                                    "ACK 3\r\n",
+                                    "MSG bob@passport.com Bob 202\r\nMIME-Version: 1.0\r\nContent-Type: text/plain; charset=UTF-8\r\nX-MMS-IM-Format: FN=Microsoft%20Sans%20Serif; EF=; CO=ff; CS=0; PF=22\r\nMessage-ID: {A4A2E7E4-2E6B-4B5F-9C9D-1D2F0B3C5E6A}\r\nChunks: 2\r\n\r\nHello ",
+                                    "MSG bob@passport.com Bob 69\r\nMessage-ID: {A4A2E7E4-2E6B-4B5F-9C9D-1D2F0B3C5E6A}\r\nChunk: 1\r\n\r\nworld",
+                                ]
+                            }
+
+                            message
+                                if message.starts_with("MSG 4 A ")
+                                    && message.contains("Chunk: 1\r\n") =>
+                            {
+                                &["ACK 4\r\n"]
+                            }
+
                             _ => &[],
                         };
 
-                        for reply in replies {
+                        for reply in replies
+                            .iter()
+                            .copied()
+                            .chain(group_replies.iter().map(String::as_str))
+                        {
                             trace!("S: {reply}");
                             if wr.write_all(reply.as_bytes()).await.is_err() {
                                 error!("Error sending to client");
@@ -0,0 +1,14 @@
+use crate::errors::sdk_error::SdkError;
+use crate::models::passport_ticket::PassportTicket;
+
+/// Obtains the ticket sent with `USR TWN S`, allowing logins to servers with different authentication methods.
+pub trait Authenticator: Send + Sync {
+    /// Returns a ticket for the user, given the challenge string the server sent after `USR TWN I`. The HTTP client
+    /// passed uses the proxy the [Client][crate::Client] was created with.
+    fn authenticate(
+        &self,
+        http_client: &reqwest::Client,
+        email: &str,
+        challenge: &str,
+    ) -> impl Future<Output = Result<PassportTicket, SdkError>> + Send;
+}
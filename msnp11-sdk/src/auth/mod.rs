@@ -0,0 +1,4 @@
+pub mod authenticator;
+pub mod passport_authenticator;
+pub mod rst_authenticator;
+pub mod ticket_authenticator;
@@ -0,0 +1,79 @@
+use crate::auth::authenticator::Authenticator;
+use crate::errors::sdk_error::SdkError;
+use crate::models::passport_ticket::PassportTicket;
+use reqwest::header::{AUTHORIZATION, HeaderMap};
+use std::error::Error;
+
+/// Authenticates through Passport 1.4, getting the login URL from Nexus then sending the credentials in the
+/// `Authorization` header.
+pub struct PassportAuthenticator {
+    password: String,
+    nexus_url: String,
+}
+
+impl PassportAuthenticator {
+    pub fn new(password: String, nexus_url: String) -> Self {
+        Self {
+            password,
+            nexus_url,
+        }
+    }
+
+    async fn get_login_srf(&self, http_client: &reqwest::Client) -> Result<String, Box<dyn Error>> {
+        let response = http_client.get(&self.nexus_url).send().await?;
+        let mut url = response
+            .headers()
+            .get("Passporturls")
+            .ok_or(SdkError::AuthenticationHeaderNotFound)?
+            .to_str()?
+            .replace("DALogin=", "");
+
+        if !url.starts_with("http") {
+            url.insert_str(0, "https://");
+        }
+
+        Ok(url)
+    }
+}
+
+impl Authenticator for PassportAuthenticator {
+    async fn authenticate(
+        &self,
+        http_client: &reqwest::Client,
+        email: &str,
+        challenge: &str,
+    ) -> Result<PassportTicket, SdkError> {
+        let login_srf = self
+            .get_login_srf(http_client)
+            .await
+            .or(Err(SdkError::CouldNotGetAuthenticationString))?;
+
+        let password = &self.password;
+        let mut headers = HeaderMap::with_capacity(1);
+        headers.insert(AUTHORIZATION, format!("Passport1.4 OrgVerb=GET,OrgURL=http%3A%2F%2Fmessenger%2Emsn%2Ecom,sign-in={email},pwd={password},{challenge}").parse().or(Err(SdkError::CouldNotGetAuthenticationString))?);
+
+        let response = http_client
+            .get(login_srf)
+            .headers(headers)
+            .send()
+            .await
+            .or(Err(SdkError::ReceivingError))?;
+
+        let authentication_info = response
+            .headers()
+            .get("Authentication-Info")
+            .ok_or(SdkError::AuthenticationHeaderNotFound)?
+            .to_str()
+            .or(Err(SdkError::CouldNotGetAuthenticationString))?;
+
+        let mut token = authentication_info.split("from-PP='");
+        token.next();
+
+        let token = token
+            .next()
+            .ok_or(SdkError::CouldNotGetAuthenticationString)?;
+
+        // Passport 1.4 doesn't say when the ticket expires
+        Ok(PassportTicket::new(token.replace("'", ""), None))
+    }
+}
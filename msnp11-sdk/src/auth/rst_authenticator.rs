@@ -0,0 +1,203 @@
+use crate::auth::authenticator::Authenticator;
+use crate::errors::sdk_error::SdkError;
+use crate::http::xml::rst_envelope::RstEnvelope;
+use crate::models::passport_ticket::PassportTicket;
+use quick_xml::escape::escape;
+use reqwest::header::{CONTENT_TYPE, HeaderValue};
+use std::error::Error;
+use std::time::{Duration, SystemTime};
+
+/// Authenticates through a SOAP `RequestSecurityToken` request, like the one sent to `RST.srf` by later clients.
+/// The challenge string is used as the policy for the `messenger.msn.com` token.
+pub struct RstAuthenticator {
+    password: String,
+    rst_url: String,
+}
+
+impl RstAuthenticator {
+    pub fn new(password: String, rst_url: String) -> Self {
+        Self { password, rst_url }
+    }
+
+    async fn request_token(
+        &self,
+        http_client: &reqwest::Client,
+        email: &str,
+        challenge: &str,
+    ) -> Result<PassportTicket, Box<dyn Error + Send + Sync>> {
+        let email = escape(email);
+        let password = escape(self.password.as_str());
+        let challenge = escape(challenge);
+
+        let xml = format!(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\
+            <Envelope xmlns=\"http://schemas.xmlsoap.org/soap/envelope/\" \
+                      xmlns:wsse=\"http://schemas.xmlsoap.org/ws/2003/06/secext\" \
+                      xmlns:saml=\"urn:oasis:names:tc:SAML:1.0:assertion\" \
+                      xmlns:wsp=\"http://schemas.xmlsoap.org/ws/2002/12/policy\" \
+                      xmlns:wsu=\"http://docs.oasis-open.org/wss/2004/01/oasis-200401-wss-wssecurity-utility-1.0.xsd\" \
+                      xmlns:wsa=\"http://schemas.xmlsoap.org/ws/2004/03/addressing\" \
+                      xmlns:wssc=\"http://schemas.xmlsoap.org/ws/2004/04/sc\" \
+                      xmlns:wst=\"http://schemas.xmlsoap.org/ws/2004/04/trust\">
+                <Header>
+                    <ps:AuthInfo xmlns:ps=\"http://schemas.microsoft.com/Passport/SoapServices/PPCRL\" Id=\"PPAuthInfo\">
+                        <ps:HostingApp>{{7108E71A-9926-4FCB-BCC9-9A9D3F32E423}}</ps:HostingApp>
+                        <ps:BinaryVersion>4</ps:BinaryVersion>
+                        <ps:UIVersion>1</ps:UIVersion>
+                        <ps:Cookies></ps:Cookies>
+                        <ps:RequestParams>AQAAAAIAAABsYwQAAAAzMDg0</ps:RequestParams>
+                    </ps:AuthInfo>
+                    <wsse:Security>
+                        <wsse:UsernameToken Id=\"user\">
+                            <wsse:Username>{email}</wsse:Username>
+                            <wsse:Password>{password}</wsse:Password>
+                        </wsse:UsernameToken>
+                    </wsse:Security>
+                </Header>
+                <Body>
+                    <ps:RequestMultipleSecurityTokens xmlns:ps=\"http://schemas.microsoft.com/Passport/SoapServices/PPCRL\" Id=\"RSTS\">
+                        <wst:RequestSecurityToken Id=\"RST0\">
+                            <wst:RequestType>http://schemas.xmlsoap.org/ws/2004/04/security/trust/Issue</wst:RequestType>
+                            <wsp:AppliesTo>
+                                <wsa:EndpointReference>
+                                    <wsa:Address>http://Passport.NET/tb</wsa:Address>
+                                </wsa:EndpointReference>
+                            </wsp:AppliesTo>
+                        </wst:RequestSecurityToken>
+                        <wst:RequestSecurityToken Id=\"RST1\">
+                            <wst:RequestType>http://schemas.xmlsoap.org/ws/2004/04/security/trust/Issue</wst:RequestType>
+                            <wsp:AppliesTo>
+                                <wsa:EndpointReference>
+                                    <wsa:Address>messenger.msn.com</wsa:Address>
+                                </wsa:EndpointReference>
+                            </wsp:AppliesTo>
+                            <wsse:PolicyReference URI=\"?{challenge}\"></wsse:PolicyReference>
+                        </wst:RequestSecurityToken>
+                    </ps:RequestMultipleSecurityTokens>
+                </Body>
+            </Envelope>"
+        );
+
+        let response = http_client
+            .post(&self.rst_url)
+            .header(CONTENT_TYPE, HeaderValue::from_static("text/xml"))
+            .body(xml)
+            .send()
+            .await?;
+
+        let xml = response.text().await?;
+        let envelope: RstEnvelope = quick_xml::de::from_str(&xml)?;
+
+        let response = envelope
+            .body
+            .collection
+            .responses
+            .into_iter()
+            .find(|response| response.applies_to.endpoint_reference.address == "messenger.msn.com")
+            .ok_or(SdkError::CouldNotGetAuthenticationString)?;
+
+        let token = response
+            .requested_security_token
+            .binary_security_token
+            .ok_or(SdkError::CouldNotGetAuthenticationString)?;
+
+        let expires_at = response
+            .lifetime
+            .and_then(|lifetime| lifetime.expires)
+            .and_then(|expires| parse_timestamp(&expires));
+
+        Ok(PassportTicket::new(token.value, expires_at))
+    }
+}
+
+/// Parses UTC timestamps like `2006-04-11T17:42:20Z`, the format used in token lifetimes.
+fn parse_timestamp(timestamp: &str) -> Option<SystemTime> {
+    let (date, time) = timestamp.trim().trim_end_matches('Z').split_once('T')?;
+    let mut date = date.split('-').map(|field| field.parse::<i64>());
+    let (year, month, day) = (date.next()?.ok()?, date.next()?.ok()?, date.next()?.ok()?);
+
+    let mut time = time.split(':');
+    let hours = time.next()?.parse::<u64>().ok()?;
+    let minutes = time.next()?.parse::<u64>().ok()?;
+    let seconds = time.next()?.parse::<f64>().ok()?;
+
+    let leap_year = year % 4 == 0 && (year % 100 != 0 || year % 400 == 0);
+    let days_in_month = match month {
+        2 if leap_year => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    };
+
+    // Up to 60 seconds, for leap seconds
+    if !(1..=12).contains(&month)
+        || !(1..=days_in_month).contains(&day)
+        || hours > 23
+        || minutes > 59
+        || !(0.0..61.0).contains(&seconds)
+    {
+        return None;
+    }
+
+    let seconds = seconds as u64;
+
+    // Days since the epoch, from Howard Hinnant's days_from_civil
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * (month + if month > 2 { -3 } else { 9 }) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    let days = u64::try_from(era * 146097 + day_of_era - 719468).ok()?;
+
+    let seconds = days * 86400 + hours * 3600 + minutes * 60 + seconds;
+    SystemTime::UNIX_EPOCH.checked_add(Duration::from_secs(seconds))
+}
+
+impl Authenticator for RstAuthenticator {
+    async fn authenticate(
+        &self,
+        http_client: &reqwest::Client,
+        email: &str,
+        challenge: &str,
+    ) -> Result<PassportTicket, SdkError> {
+        self.request_token(http_client, email, challenge)
+            .await
+            .or(Err(SdkError::CouldNotGetAuthenticationString))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse_timestamp;
+    use std::time::{Duration, SystemTime};
+
+    fn epoch(seconds: u64) -> Option<SystemTime> {
+        SystemTime::UNIX_EPOCH.checked_add(Duration::from_secs(seconds))
+    }
+
+    #[test]
+    fn parses_token_lifetime() {
+        assert_eq!(parse_timestamp("2006-04-11T17:42:20Z"), epoch(1144777340));
+        assert_eq!(parse_timestamp("2006-04-11T17:42:20.5Z"), epoch(1144777340));
+    }
+
+    #[test]
+    fn parses_leap_days() {
+        assert_eq!(parse_timestamp("2024-02-29T12:00:00Z"), epoch(1709208000));
+        assert_eq!(parse_timestamp("2000-03-01T00:00:00Z"), epoch(951868800));
+    }
+
+    #[test]
+    fn rejects_malformed_timestamps() {
+        assert_eq!(parse_timestamp("2006-04-11"), None);
+        assert_eq!(parse_timestamp("1969-12-31T23:59:59Z"), None);
+        assert_eq!(parse_timestamp("2026-13-45T99:99:00Z"), None);
+        assert_eq!(parse_timestamp("2026-00-10T12:00:00Z"), None);
+        assert_eq!(parse_timestamp("2023-02-29T12:00:00Z"), None);
+        assert_eq!(parse_timestamp("2026-04-31T12:00:00Z"), None);
+        assert_eq!(parse_timestamp("2026-04-11T24:00:00Z"), None);
+        assert_eq!(parse_timestamp("2026-04-11T12:60:00Z"), None);
+        assert_eq!(parse_timestamp("2026-04-11T12:00:61Z"), None);
+        assert_eq!(parse_timestamp("2026-04-11T12:00:1e30Z"), None);
+    }
+}
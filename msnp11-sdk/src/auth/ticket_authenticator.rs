@@ -0,0 +1,25 @@
+use crate::auth::authenticator::Authenticator;
+use crate::errors::sdk_error::SdkError;
+use crate::models::passport_ticket::PassportTicket;
+
+/// Uses a ticket obtained beforehand, skipping any HTTP requests.
+pub struct TicketAuthenticator {
+    ticket: String,
+}
+
+impl TicketAuthenticator {
+    pub fn new(ticket: String) -> Self {
+        Self { ticket }
+    }
+}
+
+impl Authenticator for TicketAuthenticator {
+    async fn authenticate(
+        &self,
+        _http_client: &reqwest::Client,
+        _email: &str,
+        _challenge: &str,
+    ) -> Result<PassportTicket, SdkError> {
+        Ok(PassportTicket::new(self.ticket.clone(), None))
+    }
+}
@@ -1,24 +1,50 @@
 use crate::MsnObject;
+use crate::auth::authenticator::Authenticator;
+use crate::auth::passport_authenticator::PassportAuthenticator;
+use crate::conversation::{Conversation, ConversationManager, SessionOpener};
+use crate::enums::add_notification_mode::AddNotificationMode;
 use crate::enums::event::Event;
 use crate::enums::internal_event::InternalEvent;
 use crate::enums::msnp_list::MsnpList;
 use crate::enums::msnp_status::MsnpStatus;
+use crate::enums::phone_property::PhoneProperty;
+use crate::enums::privacy_mode::PrivacyMode;
+use crate::enums::protocol_direction::ProtocolDirection;
+use crate::enums::server_type::ServerType;
+use crate::enums::service_url_kind::ServiceUrlKind;
+use crate::enums::transport::Transport;
 use crate::errors::contact_error::ContactError;
 use crate::errors::sdk_error::SdkError;
 #[cfg(feature = "uniffi")]
 use crate::event_handler::EventHandler;
+use crate::group_chat::GroupChat;
 #[cfg(feature = "config")]
 use crate::http::config::Config;
 use crate::http::http_client::HttpClient;
+use crate::models::challenge_keys::ChallengeKeys;
+use crate::models::client_capabilities::ClientCapabilities;
+use crate::models::contact_request::ContactRequest;
+use crate::models::passport_ticket::PassportTicket;
 use crate::models::personal_message::PersonalMessage;
 use crate::models::presence::Presence;
+use crate::models::protocol_log_entry::ProtocolLogEntry;
+use crate::models::proxy::Proxy;
+use crate::models::service_url::ServiceUrl;
 use crate::models::user_data::UserData;
 use crate::notification_server::commands::{
-    adc, adg, blp, chg, cvr, gcf, gtc, prp, reg, rem, rmg, sbp, syn, usr_i, usr_s, uux, ver, xfr,
+    adc, adg, blp, chg, cvr, gcf, gtc, pag, prp, qry, reg, rem, rmg, sbp, syn, url, usr_i, usr_s,
+    uux, ver,
 };
 use crate::notification_server::event_matcher::{into_event, into_internal_event};
+use crate::notification_server::privacy_manager::PrivacyManager;
+use crate::presence_manager::{PresenceConnection, PresenceManager};
+use crate::protocol_log::ProtocolLogger;
+use crate::protocol_log::redact;
+#[cfg(feature = "uniffi")]
+use crate::protocol_log_handler::ProtocolLogHandler;
 use crate::receive_split::receive_split;
 use crate::switchboard_server::switchboard::Switchboard;
+use crate::transport::Connector;
 use base64::{Engine as _, engine::general_purpose::STANDARD};
 use core::str;
 use log::{error, trace};
@@ -26,7 +52,6 @@ use std::sync::Arc;
 use std::sync::atomic::AtomicU32;
 use std::time::Duration;
 use tokio::io::AsyncWriteExt;
-use tokio::net::{TcpStream, lookup_host};
 use tokio::sync::{RwLock, broadcast, mpsc};
 use tokio_util::sync::CancellationToken;
 
@@ -36,51 +61,86 @@ pub struct Client {
     event_rx: async_channel::Receiver<Event>,
     ns_tx: mpsc::Sender<Vec<u8>>,
     internal_tx: broadcast::Sender<InternalEvent>,
-    tr_id: AtomicU32,
+    tr_id: Arc<AtomicU32>,
     user_data: Arc<RwLock<UserData>>,
     http_client: HttpClient,
+    connector: Connector,
+    protocol_logger: ProtocolLogger,
+    challenge_keys: Arc<RwLock<ChallengeKeys>>,
+    privacy_manager: Arc<RwLock<PrivacyManager>>,
+    presence_manager: Arc<RwLock<Option<PresenceManager>>>,
+    conversation_manager: Arc<ConversationManager>,
     cancellation_token: CancellationToken,
 }
 
 impl Client {
-    /// Connects to the server, defines the channels and returns a new instance.
+    /// Connects to the server through plain TCP, defines the channels and returns a new instance.
     pub async fn new(server: &str, port: u16) -> Result<Self, SdkError> {
-        let mut server_ips = lookup_host((server, port))
-            .await
-            .or(Err(SdkError::ResolutionError))?;
+        Self::new_with_transport(server, port, Transport::Tcp).await
+    }
+
+    /// Connects to the server using the specified [Transport], defines the channels and returns a new instance.
+    /// Switchboard sessions created or answered by this client use the same transport.
+    pub async fn new_with_transport(
+        server: &str,
+        port: u16,
+        transport: Transport,
+    ) -> Result<Self, SdkError> {
+        Self::connect(server, port, Connector::new(transport, None)).await
+    }
 
-        let server_ip = server_ips
-            .find(|ip| ip.is_ipv4())
-            .ok_or(SdkError::ResolutionError)?
-            .ip();
+    /// Connects to the server using the specified [Transport] through a [Proxy], defines the channels and returns a
+    /// new instance. The proxy is also used for Switchboards, direct P2P connections and Passport requests.
+    pub async fn new_with_proxy(
+        server: &str,
+        port: u16,
+        transport: Transport,
+        proxy: Proxy,
+    ) -> Result<Self, SdkError> {
+        Self::connect(server, port, Connector::new(transport, Some(proxy))).await
+    }
 
+    async fn connect(server: &str, port: u16, connector: Connector) -> Result<Self, SdkError> {
         let (event_tx, event_rx) = async_channel::unbounded();
         let (ns_tx, mut ns_rx) = mpsc::channel::<Vec<u8>>(256);
         let (internal_tx, _) = broadcast::channel::<InternalEvent>(256);
 
-        let socket = TcpStream::connect((server_ip, port))
-            .await
-            .or(Err(SdkError::ServerError))?;
+        let (mut rd, mut wr) = connector
+            .connect(server, port, ServerType::NotificationServer)
+            .await?;
+        let http_client = HttpClient::new(connector.http_client()?);
+        let protocol_logger = ProtocolLogger::default();
+        let privacy_manager = Arc::new(RwLock::new(PrivacyManager::default()));
 
-        let (mut rd, mut wr) = socket.into_split();
         let task_internal_tx = internal_tx.clone();
         let task_event_tx = event_tx.clone();
+        let task_protocol_logger = protocol_logger.clone();
+        let task_privacy_manager = privacy_manager.clone();
 
         let cancellation_token = CancellationToken::new();
         let task_cancellation_token = cancellation_token.clone();
 
         tokio::spawn(async move {
+            // Contact phone properties sent with SYN refer to the last listed contact
+            let mut last_contact = None;
             'outer: while let Ok(messages) =
                 receive_split(&mut rd, task_cancellation_token.clone()).await
             {
                 for message in messages {
+                    task_protocol_logger.log(
+                        ProtocolDirection::Received,
+                        ServerType::NotificationServer,
+                        &message,
+                    );
+
                     let internal_event = into_internal_event(&message);
                     if let Err(error) = task_internal_tx.send(internal_event) {
                         error!("{error}");
                     }
 
-                    let event = into_event(&message);
+                    let event = into_event(&message, &mut last_contact);
                     if let Some(event) = event {
+                        task_privacy_manager.write().await.handle_event(&event);
                         let disconnected =
                             matches!(event, Event::Disconnected | Event::LoggedInAnotherDevice);
 
@@ -107,15 +167,26 @@ impl Client {
 
         let task_event_tx = event_tx.clone();
         let task_cancellation_token = cancellation_token.clone();
+        let task_protocol_logger = protocol_logger.clone();
 
         tokio::spawn(async move {
             loop {
                 tokio::select! {
                     message = ns_rx.recv() => {
                         if let Some(message) = message {
+                            task_protocol_logger.log(
+                                ProtocolDirection::Sent,
+                                ServerType::NotificationServer,
+                                &message,
+                            );
+
                             if let Err(error) = wr.write_all(&message).await {
                                 error!("{error}")
                             }
+
+                            if let Err(error) = wr.flush().await {
+                                error!("{error}")
+                            }
                         } else {
                             break;
                         }
@@ -135,14 +206,31 @@ impl Client {
             task_cancellation_token.cancel();
         });
 
+        let tr_id = Arc::new(AtomicU32::new(0));
+        let user_data = Arc::new(RwLock::new(UserData::new()));
+        let conversation_manager = Arc::new(ConversationManager::new(SessionOpener {
+            tr_id: tr_id.clone(),
+            ns_tx: ns_tx.clone(),
+            internal_tx: internal_tx.clone(),
+            user_data: user_data.clone(),
+            connector: connector.clone(),
+            protocol_logger: protocol_logger.clone(),
+        }));
+
         Ok(Self {
             event_tx,
             event_rx,
             ns_tx,
             internal_tx,
-            tr_id: AtomicU32::new(0),
-            user_data: Arc::new(RwLock::new(UserData::new())),
-            http_client: HttpClient::new(),
+            tr_id,
+            user_data,
+            http_client,
+            connector,
+            protocol_logger,
+            challenge_keys: Arc::new(RwLock::new(ChallengeKeys::default())),
+            privacy_manager,
+            presence_manager: Arc::new(RwLock::new(None)),
+            conversation_manager,
             cancellation_token,
         })
     }
@@ -156,12 +244,12 @@ impl Client {
         tokio::spawn(async move {
             let command = "PNG\r\n";
             'outer: while ns_tx.send(command.as_bytes().to_vec()).await.is_ok() {
-                trace!("C: {command}");
+                trace!("C: {}", redact(command));
                 loop {
                     tokio::select! {
                         reply = internal_rx.recv() => {
                             if let Ok(InternalEvent::ServerReply(reply)) = reply {
-                                trace!("S: {reply}");
+                                trace!("S: {}", redact(&reply));
 
                                 let mut args = reply.split_ascii_whitespace();
                                 if args.next().unwrap_or("") == "QNG" {
@@ -194,10 +282,61 @@ impl Client {
         });
     }
 
+    fn handle_challenges(&self) {
+        let tr_id = self.tr_id.clone();
+        let ns_tx = self.ns_tx.clone();
+        let internal_tx = self.internal_tx.clone();
+        let mut internal_rx = self.internal_tx.subscribe();
+        let challenge_keys = self.challenge_keys.clone();
+        let task_cancellation_token = self.cancellation_token.clone();
+
+        tokio::spawn(async move {
+            loop {
+                tokio::select! {
+                    event = internal_rx.recv() => {
+                        if let Ok(InternalEvent::Challenge(challenge)) = event {
+                            // Answered in its own task, so challenges sent meanwhile aren't missed
+                            let tr_id = tr_id.clone();
+                            let ns_tx = ns_tx.clone();
+                            let mut reply_rx = internal_tx.subscribe();
+                            let challenge_keys = challenge_keys.read().await.clone();
+                            let cancellation_token = task_cancellation_token.clone();
+
+                            tokio::spawn(async move {
+                                tokio::select! {
+                                    result = qry::send(
+                                        &tr_id,
+                                        &ns_tx,
+                                        &mut reply_rx,
+                                        &challenge,
+                                        &challenge_keys,
+                                    ) => {
+                                        if let Err(error) = result {
+                                            error!("{error}");
+                                        }
+                                    }
+
+                                    _ = cancellation_token.cancelled() => (),
+                                }
+                            });
+                        }
+                    }
+
+                    _ = task_cancellation_token.cancelled() => {
+                        break;
+                    }
+                }
+            }
+        });
+    }
+
     fn handle_switchboard_invitations(&self) {
         let event_tx = self.event_tx.clone();
         let mut internal_rx = self.internal_tx.subscribe();
         let user_data = self.user_data.clone();
+        let connector = self.connector.clone();
+        let protocol_logger = self.protocol_logger.clone();
+        let conversation_manager = self.conversation_manager.clone();
         let task_cancellation_token = self.cancellation_token.clone();
 
         tokio::spawn(async move {
@@ -216,20 +355,36 @@ impl Client {
                                     port.as_str(),
                                     cki_string.as_str(),
                                     user_data.clone(),
+                                    &connector,
+                                    &protocol_logger,
                                 )
                                 .await;
 
-                                if let Ok(switchboard) = switchboard {
-                                    let user_data = user_data.read().await;
-                                    if let Some(ref user_email) = user_data.email
-                                        && switchboard.answer(user_email, &session_id).await.is_ok()
-                                        && let Err(error) = event_tx
-                                            .send(Event::SessionAnswered(Arc::new(switchboard)))
-                                            .await
-                                    {
-                                        error!("{error}");
-                                        break;
+                                let Ok(switchboard) = switchboard else {
+                                    continue;
+                                };
+
+                                let user_email = user_data.read().await.email.clone();
+                                let Some(user_email) = user_email else {
+                                    continue;
+                                };
+
+                                let Ok(participants) = switchboard.answer(&user_email, &session_id).await else {
+                                    continue;
+                                };
+
+                                let event = if conversation_manager.is_enabled() {
+                                    match conversation_manager.adopt(switchboard, participants).await {
+                                        Some(conversation) => Event::ConversationStarted(conversation),
+                                        None => continue,
                                     }
+                                } else {
+                                    Event::SessionAnswered(Arc::new(switchboard))
+                                };
+
+                                if let Err(error) = event_tx.send(event).await {
+                                    error!("{error}");
+                                    break;
                                 }
                             }
                     }
@@ -271,7 +426,32 @@ impl Client {
         });
     }
 
-    /// Does the MSNP authentication process. Also starts regular pings and the handler for Switchboard invitations.
+    /// Sets a closure that receives every command sent and received, including the ones of Switchboards created or answered by
+    /// this client. Credentials are redacted, so this can be enabled for debugging.
+    pub fn set_protocol_log_handler_closure<F>(&self, f: F)
+    where
+        F: Fn(ProtocolLogEntry) + Send + Sync + 'static,
+    {
+        self.protocol_logger.set_handler(Arc::new(f));
+    }
+
+    #[cfg(feature = "uniffi")]
+    /// Sets a protocol log handler that implements the [ProtocolLogHandler] trait.
+    ///
+    /// This exists for the foreign language bindings, with which generics don't
+    /// work. Prefer [`set_protocol_log_handler_closure`][Client::set_protocol_log_handler_closure] if using this SDK with Rust.
+    pub fn set_protocol_log_handler(&self, handler: Arc<dyn ProtocolLogHandler>) {
+        self.protocol_logger
+            .set_handler(Arc::new(move |entry| handler.log(entry)));
+    }
+
+    /// Sets the product ID and key used to answer challenges from the server. Defaults to the ones used by MSN Messenger 7.0.
+    pub async fn set_challenge_keys(&self, challenge_keys: ChallengeKeys) {
+        *self.challenge_keys.write().await = challenge_keys;
+    }
+
+    /// Does the MSNP authentication process. Also starts regular pings and the handlers for challenges and Switchboard
+    /// invitations.
     ///
     /// # Events
     /// If the server you're connecting to implements a Dispatch Server, then this will return a [RedirectedTo][Event::RedirectedTo] event.
@@ -284,6 +464,43 @@ impl Client {
         nexus_url: &str,
         client_name: &str,
         version: &str,
+    ) -> Result<Event, SdkError> {
+        let authenticator = PassportAuthenticator::new(password.to_string(), nexus_url.to_string());
+        self.login_with_authenticator(email, &authenticator, client_name, version)
+            .await
+    }
+
+    /// Does the MSNP authentication process, getting the ticket from the specified [Authenticator]. Also starts
+    /// regular pings and the handler for Switchboard invitations.
+    ///
+    /// # Events
+    /// Returns the same events as [login][Client::login].
+    pub async fn login_with_authenticator(
+        &self,
+        email: String,
+        authenticator: &impl Authenticator,
+        client_name: &str,
+        version: &str,
+    ) -> Result<Event, SdkError> {
+        self.login_with_cached_ticket(email, None, authenticator, client_name, version)
+            .await
+    }
+
+    /// Does the MSNP authentication process using a [PassportTicket] from a previous login, skipping the
+    /// authentication requests. The specified [Authenticator] is only used if there's no ticket or if it has expired.
+    ///
+    /// If the server rejects the ticket this returns [TicketRejected][SdkError::TicketRejected], since the server may
+    /// close the connection afterwards. Connect again with a new client and log in without the ticket.
+    ///
+    /// # Events
+    /// Returns the same events as [login][Client::login].
+    pub async fn login_with_cached_ticket(
+        &self,
+        email: String,
+        cached_ticket: Option<PassportTicket>,
+        authenticator: &impl Authenticator,
+        client_name: &str,
+        version: &str,
     ) -> Result<Event, SdkError> {
         let mut internal_rx = self.internal_tx.subscribe();
 
@@ -308,27 +525,46 @@ impl Client {
                 _ => return Err(SdkError::CouldNotGetAuthenticationString),
             };
 
-        let token = self
-            .http_client
-            .get_passport_token(&email, password, nexus_url, &authorization_string)
-            .await?;
+        let ticket = match cached_ticket.filter(|ticket| !ticket.is_expired()) {
+            Some(ticket) => {
+                usr_s::send_cached(&self.tr_id, &self.ns_tx, &mut internal_rx, &ticket.ticket)
+                    .await?;
+
+                ticket
+            }
 
-        usr_s::send(&self.tr_id, &self.ns_tx, &mut internal_rx, &token).await?;
+            None => {
+                let ticket = authenticator
+                    .authenticate(self.http_client.client(), &email, &authorization_string)
+                    .await?;
+
+                usr_s::send(&self.tr_id, &self.ns_tx, &mut internal_rx, &ticket.ticket).await?;
+                ticket
+            }
+        };
 
         {
             let mut user_data = self.user_data.write().await;
             user_data.email = Some(email);
+            user_data.ticket = Some(ticket);
         }
 
         syn::send(&self.tr_id, &self.ns_tx, &mut internal_rx).await?;
         gcf::send(&self.tr_id, &self.ns_tx, &mut internal_rx).await?;
 
         self.handle_switchboard_invitations();
+        self.handle_challenges();
         self.start_pinging();
 
         Ok(Event::Authenticated)
     }
 
+    /// Returns the ticket obtained in the last login, which can be cached and passed to
+    /// [login_with_cached_ticket][Client::login_with_cached_ticket] later.
+    pub async fn get_ticket(&self) -> Option<PassportTicket> {
+        self.user_data.read().await.ticket.clone()
+    }
+
     #[cfg(feature = "config")]
     /// Makes a request to get the config file (containing tabs and the MSN Today url) and returns it.
     pub async fn get_config(&self, config_url: &str) -> Result<Config, SdkError> {
@@ -338,11 +574,12 @@ impl Client {
             .or(Err(SdkError::ConfigRequestError))
     }
 
-    /// Sets the user's presence status.
+    /// Sets the user's presence status, advertising the capabilities set with
+    /// [set_capabilities][Client::set_capabilities].
     pub async fn set_presence(&self, presence: MsnpStatus) -> Result<(), SdkError> {
         let mut internal_rx = self.internal_tx.subscribe();
-        let presence = Presence::new_without_object(presence);
         let user_data = self.user_data.read().await;
+        let presence = Presence::new_without_object(presence, user_data.capabilities);
 
         chg::send(
             &self.tr_id,
@@ -351,7 +588,50 @@ impl Client {
             &presence,
             user_data.msn_object.as_deref(),
         )
-        .await
+        .await?;
+
+        drop(user_data);
+        self.user_data.write().await.status = Some(presence.status.clone());
+
+        if let Some(presence_manager) = self.presence_manager.read().await.as_ref() {
+            presence_manager.set_status(presence.status).await;
+        }
+
+        Ok(())
+    }
+
+    /// Attaches a [PresenceManager] to this client, which sets the status and personal message it remembers from a
+    /// previous client and starts watching for inactivity. Should be called after logging in. If the manager doesn't
+    /// remember a status yet, it uses the ones already set on this client.
+    pub async fn attach_presence_manager(
+        &self,
+        presence_manager: &PresenceManager,
+    ) -> Result<(), SdkError> {
+        presence_manager
+            .attach(PresenceConnection {
+                tr_id: self.tr_id.clone(),
+                ns_tx: self.ns_tx.clone(),
+                internal_tx: self.internal_tx.clone(),
+                user_data: self.user_data.clone(),
+                cancellation_token: self.cancellation_token.child_token(),
+            })
+            .await?;
+
+        *self.presence_manager.write().await = Some(presence_manager.clone());
+        Ok(())
+    }
+
+    /// Stops the attached [PresenceManager] from changing this client's status.
+    pub async fn detach_presence_manager(&self) {
+        if let Some(presence_manager) = self.presence_manager.write().await.take() {
+            presence_manager.detach().await;
+        }
+    }
+
+    /// Sets the features this client implements, sent the next time the presence is set. Defaults to
+    /// MSNC version 4.
+    pub async fn set_capabilities(&self, capabilities: ClientCapabilities) {
+        self.user_data.write().await.capabilities = capabilities;
     }
 
     /// Sets the user's personal message.
@@ -360,13 +640,43 @@ impl Client {
         personal_message: &PersonalMessage,
     ) -> Result<(), SdkError> {
         let mut internal_rx = self.internal_tx.subscribe();
-        uux::send(&self.tr_id, &self.ns_tx, &mut internal_rx, personal_message).await
+        uux::send(&self.tr_id, &self.ns_tx, &mut internal_rx, personal_message).await?;
+        self.user_data.write().await.personal_message = Some(personal_message.clone());
+
+        if let Some(presence_manager) = self.presence_manager.read().await.as_ref() {
+            presence_manager
+                .set_personal_message(personal_message.clone())
+                .await;
+        }
+
+        Ok(())
     }
 
     /// Sets the user's display name.
     pub async fn set_display_name(&self, display_name: &str) -> Result<(), SdkError> {
         let mut internal_rx = self.internal_tx.subscribe();
-        prp::send(&self.tr_id, &self.ns_tx, &mut internal_rx, display_name).await
+        let display_name = urlencoding::encode(display_name);
+        prp::send(
+            &self.tr_id,
+            &self.ns_tx,
+            &mut internal_rx,
+            "MFN",
+            &display_name,
+        )
+        .await
+    }
+
+    /// Sets one of the user's phone numbers or mobile settings. Setting a number to an empty string removes it.
+    pub async fn set_phone_property(&self, property: PhoneProperty) -> Result<(), SdkError> {
+        let mut internal_rx = self.internal_tx.subscribe();
+        let (property, value) = property.as_args();
+        prp::send(&self.tr_id, &self.ns_tx, &mut internal_rx, property, &value).await
+    }
+
+    /// Sends a page to a contact's mobile phone. The contact needs to have mobile paging enabled.
+    pub async fn send_page(&self, email: &str, message: &str) -> Result<(), ContactError> {
+        let mut internal_rx = self.internal_tx.subscribe();
+        pag::send(&self.tr_id, &self.ns_tx, &mut internal_rx, email, message).await
     }
 
     /// Sets a contact's display name.
@@ -394,22 +704,37 @@ impl Client {
         list: MsnpList,
     ) -> Result<Event, ContactError> {
         let mut internal_rx = self.internal_tx.subscribe();
-        adc::send(
+        let event = adc::send(
             &self.tr_id,
             &self.ns_tx,
             &mut internal_rx,
             email,
             display_name,
-            list,
+            list.clone(),
         )
-        .await
+        .await?;
+
+        let mut privacy_manager = self.privacy_manager.write().await;
+        privacy_manager.added(email, &list);
+        privacy_manager.handle_event(&event);
+        Ok(event)
     }
 
     /// Removes a contact from a specified list (except the forward list, which requires calling
     /// [remove_contact_from_forward_list][Client::remove_contact_from_forward_list]).
     pub async fn remove_contact(&self, email: &str, list: MsnpList) -> Result<(), ContactError> {
         let mut internal_rx = self.internal_tx.subscribe();
-        rem::send(&self.tr_id, &self.ns_tx, &mut internal_rx, email, list).await
+        rem::send(
+            &self.tr_id,
+            &self.ns_tx,
+            &mut internal_rx,
+            email,
+            list.clone(),
+        )
+        .await?;
+
+        self.privacy_manager.write().await.removed(email, &list);
+        Ok(())
     }
 
     /// Removes a contact from the forward list.
@@ -418,50 +743,158 @@ impl Client {
         rem::send_with_forward_list(&self.tr_id, &self.ns_tx, &mut internal_rx, guid).await
     }
 
-    /// Blocks a contact.
+    /// Blocks a contact, moving it from the allow list to the block list. Returns
+    /// [AlreadyInList][ContactError::AlreadyInList] if the contact is already blocked.
     pub async fn block_contact(&self, email: &str) -> Result<(), ContactError> {
+        self.move_contact(email, MsnpList::BlockList).await
+    }
+
+    /// Unblocks a contact, moving it from the block list to the allow list. Returns
+    /// [AlreadyInList][ContactError::AlreadyInList] if the contact is already allowed.
+    pub async fn unblock_contact(&self, email: &str) -> Result<(), ContactError> {
+        self.move_contact(email, MsnpList::AllowList).await
+    }
+
+    /// Returns whether a contact can see your presence and talk to you, according to the privacy mode and the allow
+    /// and block lists.
+    pub async fn is_contact_allowed(&self, email: &str) -> bool {
+        self.privacy_manager.read().await.is_allowed(email)
+    }
+
+    /// Returns the current privacy mode, if already received from the server.
+    pub async fn get_privacy_mode(&self) -> Option<PrivacyMode> {
+        self.privacy_manager.read().await.mode()
+    }
+
+    /// Returns the people who added you to their contact list and haven't been allowed or blocked yet.
+    pub async fn get_contact_requests(&self) -> Vec<ContactRequest> {
+        self.privacy_manager.read().await.requests()
+    }
+
+    /// Accepts a contact request by adding the contact to the allow list, optionally also adding it to the forward
+    /// list and to a group.
+    pub async fn accept_contact_request(
+        &self,
+        email: &str,
+        add_to_forward_list: bool,
+        group_guid: Option<&str>,
+    ) -> Result<(), ContactError> {
+        let request = self
+            .privacy_manager
+            .read()
+            .await
+            .request(email)
+            .cloned()
+            .ok_or(ContactError::NoPendingRequest)?;
+
+        match self.move_contact(email, MsnpList::AllowList).await {
+            Ok(()) | Err(ContactError::AlreadyInList) => (),
+            Err(error) => return Err(error),
+        }
+
         let mut internal_rx = self.internal_tx.subscribe();
-        adc::send(
-            &self.tr_id,
-            &self.ns_tx,
-            &mut internal_rx,
-            email,
-            email,
-            MsnpList::BlockList,
-        )
-        .await?;
+        if add_to_forward_list {
+            let event = adc::send(
+                &self.tr_id,
+                &self.ns_tx,
+                &mut internal_rx,
+                email,
+                &request.display_name,
+                MsnpList::ForwardList,
+            )
+            .await?;
 
-        rem::send(
-            &self.tr_id,
-            &self.ns_tx,
-            &mut internal_rx,
-            email,
-            MsnpList::AllowList,
-        )
-        .await
+            if let Some(group_guid) = group_guid
+                && let Event::ContactInForwardList { guid, .. } = &event
+            {
+                adc::send_with_group(&self.tr_id, &self.ns_tx, &mut internal_rx, guid, group_guid)
+                    .await?;
+            }
+        }
+
+        self.finish_contact_request(email).await
     }
 
-    /// Unblocks a contact.
-    pub async fn unblock_contact(&self, email: &str) -> Result<(), ContactError> {
+    /// Rejects a contact request, optionally adding the contact to the block list.
+    pub async fn reject_contact_request(
+        &self,
+        email: &str,
+        block: bool,
+    ) -> Result<(), ContactError> {
+        if self.privacy_manager.read().await.request(email).is_none() {
+            return Err(ContactError::NoPendingRequest);
+        }
+
+        if block {
+            match self.move_contact(email, MsnpList::BlockList).await {
+                Ok(()) | Err(ContactError::AlreadyInList) => (),
+                Err(error) => return Err(error),
+            }
+        }
+
+        self.finish_contact_request(email).await
+    }
+
+    async fn finish_contact_request(&self, email: &str) -> Result<(), ContactError> {
+        if self.privacy_manager.read().await.is_in_pending_list(email) {
+            self.remove_contact(email, MsnpList::PendingList).await?;
+        }
+
+        self.privacy_manager.write().await.remove_request(email);
+        Ok(())
+    }
+
+    async fn move_contact(&self, email: &str, list: MsnpList) -> Result<(), ContactError> {
+        let transition = self.privacy_manager.read().await.transition(email, list)?;
+
         let mut internal_rx = self.internal_tx.subscribe();
-        adc::send(
-            &self.tr_id,
-            &self.ns_tx,
-            &mut internal_rx,
-            email,
-            email,
-            MsnpList::AllowList,
-        )
-        .await?;
+        if let Some(list) = &transition.remove_from {
+            rem::send(
+                &self.tr_id,
+                &self.ns_tx,
+                &mut internal_rx,
+                email,
+                list.clone(),
+            )
+            .await?;
 
-        rem::send(
-            &self.tr_id,
-            &self.ns_tx,
-            &mut internal_rx,
-            email,
-            MsnpList::BlockList,
-        )
-        .await
+            self.privacy_manager.write().await.removed(email, list);
+        }
+
+        if let Some(list) = transition.add_to {
+            if let Err(error) = adc::send(
+                &self.tr_id,
+                &self.ns_tx,
+                &mut internal_rx,
+                email,
+                email,
+                list.clone(),
+            )
+            .await
+            {
+                // Put the contact back, so it isn't left out of both lists
+                if let Some(previous) = &transition.remove_from
+                    && adc::send(
+                        &self.tr_id,
+                        &self.ns_tx,
+                        &mut internal_rx,
+                        email,
+                        email,
+                        previous.clone(),
+                    )
+                    .await
+                    .is_ok()
+                {
+                    self.privacy_manager.write().await.added(email, previous);
+                }
+
+                return Err(error);
+            }
+
+            self.privacy_manager.write().await.added(email, &list);
+        }
+
+        Ok(())
     }
 
     /// Creates a new contact group.
@@ -502,38 +935,62 @@ impl Client {
         rem::send_with_group(&self.tr_id, &self.ns_tx, &mut internal_rx, guid, group_guid).await
     }
 
-    /// Sets the GTC value, which can be either `A` or `N`.
-    pub async fn set_gtc(&self, gtc: &str) -> Result<(), SdkError> {
+    /// Sets whether to be prompted when someone adds you to their contact list.
+    pub async fn set_gtc(&self, gtc: AddNotificationMode) -> Result<(), SdkError> {
         let mut internal_rx = self.internal_tx.subscribe();
         gtc::send(&self.tr_id, &self.ns_tx, &mut internal_rx, gtc).await
     }
 
-    /// Sets the BLP value, which can be either `AL` or `BL`.
-    pub async fn set_blp(&self, blp: &str) -> Result<(), SdkError> {
+    /// Sets who can see your presence and talk to you. Does nothing if the mode is already set.
+    pub async fn set_blp(&self, blp: PrivacyMode) -> Result<(), SdkError> {
+        if self.privacy_manager.read().await.mode() == Some(blp) {
+            return Ok(());
+        }
+
         let mut internal_rx = self.internal_tx.subscribe();
-        blp::send(&self.tr_id, &self.ns_tx, &mut internal_rx, blp).await
+        blp::send(&self.tr_id, &self.ns_tx, &mut internal_rx, blp).await?;
+        self.privacy_manager.write().await.set_mode(blp);
+        Ok(())
     }
 
-    /// Creates a new Switchboard session and invites the specified contact to it.
-    pub async fn create_session(&self, email: &str) -> Result<Switchboard, SdkError> {
+    /// Requests the URLs needed to open a Hotmail or profile page already authenticated.
+    pub async fn request_service_url(&self, kind: ServiceUrlKind) -> Result<ServiceUrl, SdkError> {
         let mut internal_rx = self.internal_tx.subscribe();
-        let switchboard = xfr::send(
-            &self.tr_id,
-            &self.ns_tx,
-            &mut internal_rx,
-            self.user_data.clone(),
-        )
-        .await?;
-
-        let user_data = self.user_data.read().await;
-        let user_email = user_data.email.as_ref().ok_or(SdkError::NotLoggedIn)?;
+        url::send(&self.tr_id, &self.ns_tx, &mut internal_rx, &kind).await
+    }
 
-        switchboard.login(user_email).await?;
+    /// Creates a new Switchboard session and invites the specified contact to it.
+    pub async fn create_session(&self, email: &str) -> Result<Switchboard, SdkError> {
+        let switchboard = self.conversation_manager.session_opener().open().await?;
         switchboard.invite(email).await?;
 
         Ok(switchboard)
     }
 
+    /// Opens a new Switchboard session for a group chat. Contacts are invited to it with [GroupChat::invite].
+    pub async fn create_group_chat(&self) -> Result<Arc<GroupChat>, SdkError> {
+        let switchboard = self.conversation_manager.session_opener().open().await?;
+        Ok(Arc::new(GroupChat::new(Arc::new(switchboard)).await))
+    }
+
+    /// Makes answered Switchboard invitations go to conversations instead of being sent as
+    /// [SessionAnswered][Event::SessionAnswered] events. Invitations from the same participants as an existing
+    /// conversation continue it, others are sent as [ConversationStarted][Event::ConversationStarted].
+    pub fn enable_conversations(&self) {
+        self.conversation_manager.enable();
+    }
+
+    /// Returns the conversation with the specified contacts, creating it if there isn't one yet. No session is opened
+    /// until something is sent.
+    pub async fn get_conversation(&self, participants: &[&str]) -> Arc<Conversation> {
+        self.conversation_manager.get(participants).await
+    }
+
+    /// Returns every conversation this client has.
+    pub async fn get_conversations(&self) -> Vec<Arc<Conversation>> {
+        self.conversation_manager.get_all().await
+    }
+
     /// Sets the user's display picture, returning a standard base64 encoded hash of it.
     /// This method uses the picture's binary data, and scaling down to a size like 200x200 beforehand is recommended.
     pub async fn set_display_picture(&self, display_picture: Vec<u8>) -> Result<String, SdkError> {
@@ -574,7 +1031,7 @@ impl Client {
     /// Disconnects from the server.
     pub async fn disconnect(&self) -> Result<(), SdkError> {
         let command = "OUT\r\n";
-        trace!("C: {command}");
+        trace!("C: {}", redact(command));
 
         self.ns_tx
             .send(command.as_bytes().to_vec())
@@ -0,0 +1,330 @@
+use crate::enums::event::Event;
+use crate::enums::internal_event::InternalEvent;
+use crate::errors::messaging_error::MessagingError;
+use crate::errors::sdk_error::SdkError;
+#[cfg(feature = "uniffi")]
+use crate::event_handler::EventHandler;
+use crate::group_chat::GroupChat;
+use crate::models::plain_text::PlainText;
+use crate::models::user_data::UserData;
+use crate::notification_server::commands::xfr;
+use crate::protocol_log::ProtocolLogger;
+use crate::switchboard_server::event_fanout::EventFanout;
+use crate::switchboard_server::switchboard::Switchboard;
+use crate::transport::Connector;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+use tokio::sync::{Mutex, RwLock, broadcast, mpsc};
+use tokio_util::sync::CancellationToken;
+
+/// What's needed from the client to open new Switchboard sessions.
+#[derive(Debug, Clone)]
+pub(crate) struct SessionOpener {
+    pub(crate) tr_id: Arc<AtomicU32>,
+    pub(crate) ns_tx: mpsc::Sender<Vec<u8>>,
+    pub(crate) internal_tx: broadcast::Sender<InternalEvent>,
+    pub(crate) user_data: Arc<RwLock<UserData>>,
+    pub(crate) connector: Connector,
+    pub(crate) protocol_logger: ProtocolLogger,
+}
+
+impl SessionOpener {
+    /// Requests a new Switchboard with `XFR SB` and logs in to it.
+    pub(crate) async fn open(&self) -> Result<Switchboard, SdkError> {
+        let mut internal_rx = self.internal_tx.subscribe();
+        let switchboard = xfr::send(
+            &self.tr_id,
+            &self.ns_tx,
+            &mut internal_rx,
+            self.user_data.clone(),
+            &self.connector,
+            &self.protocol_logger,
+        )
+        .await?;
+
+        let user_data = self.user_data.read().await;
+        let user_email = user_data.email.as_ref().ok_or(SdkError::NotLoggedIn)?;
+
+        switchboard.login(user_email).await?;
+        Ok(switchboard)
+    }
+}
+
+/// A conversation with one or more contacts that outlives Switchboard sessions. Messages sent to it reuse the current
+/// session, invite participants back if they left or open a new session if it was closed, and are queued while that
+/// happens. Events from every session used by the conversation are received through its own handlers.
+#[derive(Debug)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Object))]
+pub struct Conversation {
+    participants: Vec<String>,
+    switchboard: Mutex<Option<Arc<Switchboard>>>,
+    forwarding: Mutex<Option<CancellationToken>>,
+    events: Arc<EventFanout>,
+    session_opener: SessionOpener,
+}
+
+impl Conversation {
+    fn new(participants: Vec<String>, session_opener: SessionOpener) -> Self {
+        Self {
+            participants,
+            switchboard: Mutex::new(None),
+            forwarding: Mutex::new(None),
+            events: Arc::new(EventFanout::default()),
+            session_opener,
+        }
+    }
+
+    /// Adds a handler closure. If you're using this SDK with Rust, not through a foreign binding, then this is the preferred method of
+    /// handling events.
+    pub fn add_event_handler_closure<F, R>(&self, f: F)
+    where
+        F: Fn(Event) -> R + Send + 'static,
+        R: Future<Output = ()> + Send,
+    {
+        let event_rx = self.events.subscribe();
+        tokio::spawn(async move {
+            while let Ok(event) = event_rx.recv().await {
+                f(event).await;
+            }
+        });
+    }
+
+    #[cfg(feature = "uniffi")]
+    /// Adds a new handler that implements the [EventHandler] trait.
+    ///
+    /// This exists for the foreign language bindings, with which generics don't
+    /// work. Prefer [`add_event_handler_closure`][Conversation::add_event_handler_closure] if using this SDK with Rust.
+    pub fn add_event_handler(&self, handler: Arc<dyn EventHandler>) {
+        let event_rx = self.events.subscribe();
+        tokio::spawn(async move {
+            while let Ok(event) = event_rx.recv().await {
+                handler.handle(event).await;
+            }
+        });
+    }
+
+    /// Returns the contacts this conversation is with.
+    pub fn get_participants(&self) -> Vec<String> {
+        self.participants.clone()
+    }
+
+    /// Returns the current Switchboard session, if one was opened and is still connected.
+    pub async fn get_switchboard(&self) -> Option<Arc<Switchboard>> {
+        self.switchboard
+            .lock()
+            .await
+            .clone()
+            .filter(|switchboard| switchboard.is_connected())
+    }
+
+    /// Sends a plain text message, opening a session first if needed.
+    pub async fn send_text_message(&self, message: &PlainText) -> Result<(), MessagingError> {
+        let mut switchboard = self.switchboard.lock().await;
+        self.ensure_session(&mut switchboard)
+            .await?
+            .send_text_message(message)
+            .await
+    }
+
+    /// Sends a nudge, opening a session first if needed.
+    pub async fn send_nudge(&self) -> Result<(), MessagingError> {
+        let mut switchboard = self.switchboard.lock().await;
+        self.ensure_session(&mut switchboard)
+            .await?
+            .send_nudge()
+            .await
+    }
+
+    /// Tells the contacts the user is writing if there's a session open, limited like
+    /// [Switchboard::notify_typing]. No session is opened just for this.
+    pub async fn notify_typing(&self) -> Result<(), MessagingError> {
+        match self.get_switchboard().await {
+            Some(switchboard) => switchboard.notify_typing().await,
+            None => Ok(()),
+        }
+    }
+
+    /// Moves the conversation's session, opening one first if needed, to a [GroupChat] other contacts can be invited
+    /// to, like inviting someone to a conversation window. The conversation opens a new session the next time
+    /// something is sent.
+    pub async fn convert_to_group(&self) -> Result<Arc<GroupChat>, MessagingError> {
+        let mut switchboard = self.switchboard.lock().await;
+        let current = self.ensure_session(&mut switchboard).await?;
+
+        if let Some(forwarding) = self.forwarding.lock().await.take() {
+            forwarding.cancel();
+        }
+
+        switchboard.take();
+        Ok(Arc::new(GroupChat::new(current).await))
+    }
+
+    /// Makes sure there's a connected session with every participant in it.
+    async fn ensure_session(
+        &self,
+        switchboard: &mut Option<Arc<Switchboard>>,
+    ) -> Result<Arc<Switchboard>, MessagingError> {
+        let current = match switchboard.as_ref() {
+            Some(current) if current.is_connected() => current.clone(),
+            _ => {
+                let new = Arc::new(
+                    self.session_opener
+                        .open()
+                        .await
+                        .or(Err(MessagingError::CouldNotStartSession))?,
+                );
+
+                self.forward_events(&new).await;
+                *switchboard = Some(new.clone());
+                new
+            }
+        };
+
+        let present = current.get_participants().await;
+        for participant in &self.participants {
+            if !present.contains(participant) {
+                current
+                    .invite_and_wait(participant)
+                    .await
+                    .map_err(|error| match error {
+                        SdkError::ContactIsOffline => MessagingError::ContactIsOffline,
+                        _ => MessagingError::CouldNotStartSession,
+                    })?;
+            }
+        }
+
+        Ok(current)
+    }
+
+    /// Uses a session the user was invited to, replacing the current one.
+    async fn adopt(&self, new: Arc<Switchboard>) {
+        let mut switchboard = self.switchboard.lock().await;
+        if let Some(previous) = switchboard.take()
+            && previous.is_connected()
+        {
+            let _ = previous.disconnect().await;
+        }
+
+        self.forward_events(&new).await;
+        *switchboard = Some(new);
+    }
+
+    async fn forward_events(&self, switchboard: &Switchboard) {
+        let event_rx = switchboard.event_receiver();
+        let events = self.events.clone();
+        let cancellation_token = CancellationToken::new();
+        *self.forwarding.lock().await = Some(cancellation_token.clone());
+
+        tokio::spawn(async move {
+            loop {
+                tokio::select! {
+                    event = event_rx.recv() => {
+                        let Ok(event) = event else {
+                            break;
+                        };
+
+                        // The conversation continues in a new session
+                        if matches!(event, Event::Disconnected) {
+                            continue;
+                        }
+
+                        if events.send(event).is_err() {
+                            break;
+                        }
+                    }
+
+                    _ = cancellation_token.cancelled() => break,
+                }
+            }
+        });
+    }
+}
+
+/// Keeps the client's conversations, keyed by their participants.
+#[derive(Debug)]
+pub(crate) struct ConversationManager {
+    enabled: AtomicBool,
+    conversations: Mutex<Vec<Arc<Conversation>>>,
+    session_opener: SessionOpener,
+}
+
+impl ConversationManager {
+    pub(crate) fn new(session_opener: SessionOpener) -> Self {
+        Self {
+            enabled: AtomicBool::new(false),
+            conversations: Mutex::new(Vec::new()),
+            session_opener,
+        }
+    }
+
+    pub(crate) fn enable(&self) {
+        self.enabled.store(true, Ordering::SeqCst);
+    }
+
+    pub(crate) fn is_enabled(&self) -> bool {
+        self.enabled.load(Ordering::SeqCst)
+    }
+
+    pub(crate) fn session_opener(&self) -> &SessionOpener {
+        &self.session_opener
+    }
+
+    /// Returns the conversation with these participants, creating it if needed.
+    pub(crate) async fn get(&self, participants: &[&str]) -> Arc<Conversation> {
+        let participants = normalize(participants.iter().map(|email| email.to_string()));
+        let mut conversations = self.conversations.lock().await;
+
+        if let Some(conversation) = conversations
+            .iter()
+            .find(|conversation| conversation.participants == participants)
+        {
+            return conversation.clone();
+        }
+
+        let conversation = Arc::new(Conversation::new(participants, self.session_opener.clone()));
+
+        conversations.push(conversation.clone());
+        conversation
+    }
+
+    pub(crate) async fn get_all(&self) -> Vec<Arc<Conversation>> {
+        self.conversations.lock().await.clone()
+    }
+
+    /// Merges an answered session into the conversation with the participants that were in it when answering.
+    /// Returns the conversation if it's a new one.
+    pub(crate) async fn adopt(
+        &self,
+        switchboard: Switchboard,
+        participants: Vec<String>,
+    ) -> Option<Arc<Conversation>> {
+        let participants = normalize(participants);
+        let switchboard = Arc::new(switchboard);
+        let mut conversations = self.conversations.lock().await;
+
+        if let Some(conversation) = conversations
+            .iter()
+            .find(|conversation| conversation.participants == participants)
+        {
+            conversation.adopt(switchboard).await;
+            return None;
+        }
+
+        let conversation = Arc::new(Conversation::new(participants, self.session_opener.clone()));
+
+        conversation.adopt(switchboard).await;
+        conversations.push(conversation.clone());
+        Some(conversation)
+    }
+}
+
+fn normalize(participants: impl IntoIterator<Item = String>) -> Vec<String> {
+    let mut participants: Vec<String> = participants
+        .into_iter()
+        .map(|email| email.to_lowercase())
+        .collect();
+
+    participants.sort();
+    participants.dedup();
+    participants
+}
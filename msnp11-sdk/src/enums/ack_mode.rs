@@ -0,0 +1,32 @@
+/// How the Switchboard acknowledges a MSG, sent as its second argument.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Enum))]
+pub enum AckMode {
+    /// No acknowledgement (`U`).
+    Unacknowledged,
+
+    /// `NAK` only if delivery failed (`N`).
+    Negative,
+
+    /// `ACK` or `NAK` (`A`).
+    Acknowledged,
+
+    /// `ACK` or `NAK`, for data messages (`D`).
+    Data,
+}
+
+impl AckMode {
+    pub(crate) fn as_str(&self) -> &'static str {
+        match self {
+            AckMode::Unacknowledged => "U",
+            AckMode::Negative => "N",
+            AckMode::Acknowledged => "A",
+            AckMode::Data => "D",
+        }
+    }
+
+    /// Whether the server replies to successful deliveries.
+    pub(crate) fn is_acknowledged(&self) -> bool {
+        matches!(self, AckMode::Acknowledged | AckMode::Data)
+    }
+}
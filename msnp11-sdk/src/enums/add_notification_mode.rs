@@ -0,0 +1,27 @@
+/// What happens when someone adds you to their contact list, sent with `GTC`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Enum))]
+pub enum AddNotificationMode {
+    /// Prompt before letting them see you (`A`).
+    Prompt,
+
+    /// Don't prompt (`N`).
+    DontPrompt,
+}
+
+impl AddNotificationMode {
+    pub(crate) fn from_str(value: &str) -> Option<Self> {
+        match value {
+            "A" => Some(AddNotificationMode::Prompt),
+            "N" => Some(AddNotificationMode::DontPrompt),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn as_str(&self) -> &'static str {
+        match self {
+            AddNotificationMode::Prompt => "A",
+            AddNotificationMode::DontPrompt => "N",
+        }
+    }
+}
@@ -0,0 +1,29 @@
+/// Kind of media shown in a [CurrentMedia][crate::models::current_media::CurrentMedia].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Enum))]
+pub enum CurrentMediaType {
+    Music,
+    Games,
+    Office,
+    Other(String),
+}
+
+impl CurrentMediaType {
+    pub(crate) fn from_str(value: &str) -> Self {
+        match value {
+            "Music" => CurrentMediaType::Music,
+            "Games" => CurrentMediaType::Games,
+            "Office" => CurrentMediaType::Office,
+            _ => CurrentMediaType::Other(value.to_string()),
+        }
+    }
+
+    pub(crate) fn as_str(&self) -> &str {
+        match self {
+            CurrentMediaType::Music => "Music",
+            CurrentMediaType::Games => "Games",
+            CurrentMediaType::Office => "Office",
+            CurrentMediaType::Other(value) => value,
+        }
+    }
+}
@@ -1,9 +1,19 @@
+use crate::conversation::Conversation;
+use crate::enums::add_notification_mode::AddNotificationMode;
 use crate::enums::msnp_list::MsnpList;
+use crate::enums::phone_property::PhoneProperty;
+use crate::enums::privacy_mode::PrivacyMode;
+use crate::models::client_capabilities::ClientCapabilities;
+use crate::models::custom_emoticon::CustomEmoticon;
 #[cfg(feature = "file-transfers")]
 use crate::models::file_transfer_request::FileTransferRequest;
+use crate::models::message_handle::MessageHandle;
+use crate::models::mime_header::MimeHeader;
+use crate::models::participant::Participant;
 use crate::models::personal_message::PersonalMessage;
 use crate::models::plain_text::PlainText;
 use crate::models::presence::Presence;
+use crate::models::profile::Profile;
 use crate::switchboard_server::switchboard::Switchboard;
 use std::sync::Arc;
 
@@ -18,14 +28,23 @@ pub enum Event {
     Authenticated,
 
     /// GTC value stored in the server.
-    Gtc(String),
+    Gtc(AddNotificationMode),
 
     /// BLP value stored in the server.
-    Blp(String),
+    Blp(PrivacyMode),
 
     /// User display name stored in the server.
     DisplayName(String),
 
+    /// One of the user's phone numbers or mobile settings stored in the server.
+    UserPhoneProperty(PhoneProperty),
+
+    /// One of a contact's phone numbers or mobile settings.
+    ContactPhoneProperty {
+        email: String,
+        property: PhoneProperty,
+    },
+
     /// A contact group
     Group { name: String, guid: String },
 
@@ -77,21 +96,63 @@ pub enum Event {
     /// An invitation to a Switchboard session was accepted.
     SessionAnswered(Arc<Switchboard>),
 
+    /// Someone started a conversation. Sent instead of [SessionAnswered][Event::SessionAnswered] once conversations
+    /// are enabled, and only if there's no conversation with the same participants already.
+    ConversationStarted(Arc<Conversation>),
+
     /// New text message.
     TextMessage { email: String, message: PlainText },
 
     /// New nudge.
     Nudge { email: String },
 
+    /// Custom emoticons the contact declared for the next message.
+    CustomEmoticons {
+        email: String,
+        emoticons: Vec<CustomEmoticon>,
+    },
+
+    /// A message sent without waiting was acknowledged by the server.
+    MessageDelivered { handle: MessageHandle },
+
+    /// A message sent without waiting couldn't be delivered to every participant, or the server closed the session
+    /// before acknowledging it.
+    MessageFailed { handle: MessageHandle },
+
+    /// Message with a content type the SDK doesn't handle. `MIME-Version` and `Content-Type` aren't included in the
+    /// headers, and the body is always UTF-8 text, since messages that aren't are dropped.
+    RawMessage {
+        email: String,
+        content_type: String,
+        headers: Vec<MimeHeader>,
+        body: Vec<u8>,
+    },
+
     /// Contact is writing...
     TypingNotification { email: String },
 
-    /// New user joined the Switchboard.
-    ParticipantInSwitchboard { email: String },
+    /// Contact started writing. Sent once, on the first [TypingNotification][Event::TypingNotification].
+    TypingStarted { email: String },
+
+    /// Contact stopped writing: they sent a message, left, or no notification arrived for 6 seconds.
+    TypingStopped { email: String },
+
+    /// New user joined the Switchboard, or was already in it when answering an invitation.
+    ParticipantInSwitchboard {
+        email: String,
+        display_name: String,
+        capabilities: Option<ClientCapabilities>,
+    },
+
+    /// Every participant already in the session was received after answering an invitation.
+    ParticipantRosterComplete { participants: Vec<Participant> },
 
     /// A user left the Switchboard.
     ParticipantLeftSwitchboard { email: String },
 
+    /// Everyone but one contact left a [GroupChat][crate::GroupChat].
+    GroupChatBecameOneToOne { email: String },
+
     /// A contact's display picture was transferred.
     DisplayPicture { email: String, data: Vec<u8> },
 
@@ -104,9 +165,47 @@ pub enum Event {
         request: FileTransferRequest,
     },
 
+    /// The user's profile, sent after logging in.
+    Profile(Profile),
+
+    /// Number of unread emails in the Hotmail inbox and other folders, sent after logging in.
+    InboxStatus { unread: u32, folders_unread: u32 },
+
+    /// A new email was received.
+    NewEmail {
+        from: String,
+        from_address: String,
+        subject: String,
+        folder: String,
+    },
+
+    /// Emails were moved or deleted, changing the number of unread emails in the folders.
+    MailboxActivity {
+        source_folder: String,
+        destination_folder: String,
+        message_delta: i32,
+    },
+
     /// The server has warned it will shut down for maintenance.
     ServerMaintenanceScheduled { time_remaining: u32 },
 
+    /// The previously scheduled maintenance was cancelled.
+    ServerMaintenanceCancelled,
+
+    /// A system message of an unknown type, with its arguments in order.
+    SystemMessage {
+        message_type: u32,
+        args: Vec<String>,
+    },
+
+    /// An MSN Alert. The action URL is the page to be opened when the alert is clicked.
+    Alert {
+        id: String,
+        site_url: String,
+        action_url: Option<String>,
+        text: String,
+    },
+
     /// Disconnected because the user logged in on another device.
     LoggedInAnotherDevice,
 
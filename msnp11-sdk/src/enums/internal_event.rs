@@ -9,6 +9,7 @@ pub(crate) enum InternalEvent {
     },
 
     GotAuthorizationString(String),
+    Challenge(String),
     RedirectedTo {
         server: String,
         port: u16,
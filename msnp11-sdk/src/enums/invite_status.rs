@@ -0,0 +1,25 @@
+/// Outcome of inviting a contact to a [GroupChat][crate::GroupChat].
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Enum))]
+pub enum InviteStatus {
+    /// The contact joined the session.
+    Joined,
+
+    /// The contact was already in the session, so no invitation was sent.
+    AlreadyInSession,
+
+    /// The contact is offline or blocked the user, which the server doesn't tell apart.
+    Offline,
+
+    /// The server rejected the email.
+    InvalidContact,
+
+    /// The contact was invited but didn't join in time.
+    NoAnswer,
+
+    /// The contact was invited and declined, leaving without joining.
+    Declined,
+
+    /// The invitation couldn't be sent.
+    Failed,
+}
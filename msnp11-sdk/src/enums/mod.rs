@@ -1,4 +1,16 @@
+pub mod ack_mode;
+pub mod add_notification_mode;
+pub mod current_media_type;
 pub mod event;
 pub mod internal_event;
+pub mod invite_status;
 pub mod msnp_list;
 pub mod msnp_status;
+pub mod phone_property;
+pub mod privacy_mode;
+pub mod protocol_direction;
+pub mod proxy_type;
+pub mod server_type;
+pub mod service_url_kind;
+pub mod text_token;
+pub mod transport;
@@ -5,7 +5,7 @@ pub enum MsnpList {
     /// Your contact list as it appears in the client.
     ForwardList,
 
-    /// List of people allowed to talk to you and see your presence (only applies if using a [BLP][crate::client::Client::set_blp] of `BL`).
+    /// List of people allowed to talk to you and see your presence (only applies if using a [BLP][crate::client::Client::set_blp] of [BlockUnlisted][crate::enums::privacy_mode::PrivacyMode::BlockUnlisted]).
     AllowList,
 
     /// What contacts are blocked.
@@ -0,0 +1,46 @@
+use std::borrow::Cow;
+
+/// Phone numbers and mobile settings, set with `PRP` for the user and sent with `BPR` for contacts. An empty number means
+/// it isn't set.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Enum))]
+pub enum PhoneProperty {
+    HomePhone(String),
+    WorkPhone(String),
+    MobilePhone(String),
+    /// Whether contacts can send pages to the mobile phone.
+    MobilePaging(bool),
+    /// Whether an MSN Mobile device is enabled.
+    MobileDevice(bool),
+}
+
+impl PhoneProperty {
+    pub(crate) fn from_args(property: &str, value: &str) -> Option<Self> {
+        let number = || {
+            urlencoding::decode(value)
+                .unwrap_or(Cow::from(value))
+                .to_string()
+        };
+
+        match property {
+            "PHH" => Some(PhoneProperty::HomePhone(number())),
+            "PHW" => Some(PhoneProperty::WorkPhone(number())),
+            "PHM" => Some(PhoneProperty::MobilePhone(number())),
+            "MOB" => Some(PhoneProperty::MobilePaging(value == "Y")),
+            "MBE" => Some(PhoneProperty::MobileDevice(value == "Y")),
+            _ => None,
+        }
+    }
+
+    /// Returns the property name and its encoded value.
+    pub(crate) fn as_args(&self) -> (&'static str, String) {
+        let enabled = |enabled: &bool| if *enabled { "Y" } else { "N" }.to_string();
+        match self {
+            PhoneProperty::HomePhone(number) => ("PHH", urlencoding::encode(number).to_string()),
+            PhoneProperty::WorkPhone(number) => ("PHW", urlencoding::encode(number).to_string()),
+            PhoneProperty::MobilePhone(number) => ("PHM", urlencoding::encode(number).to_string()),
+            PhoneProperty::MobilePaging(value) => ("MOB", enabled(value)),
+            PhoneProperty::MobileDevice(value) => ("MBE", enabled(value)),
+        }
+    }
+}
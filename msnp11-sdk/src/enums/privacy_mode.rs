@@ -0,0 +1,27 @@
+/// Who can see your presence and talk to you, sent with `BLP`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Enum))]
+pub enum PrivacyMode {
+    /// Everyone except the people in the block list (`AL`).
+    AllowUnlisted,
+
+    /// Only the people in the allow list (`BL`).
+    BlockUnlisted,
+}
+
+impl PrivacyMode {
+    pub(crate) fn from_str(value: &str) -> Option<Self> {
+        match value {
+            "AL" => Some(PrivacyMode::AllowUnlisted),
+            "BL" => Some(PrivacyMode::BlockUnlisted),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn as_str(&self) -> &'static str {
+        match self {
+            PrivacyMode::AllowUnlisted => "AL",
+            PrivacyMode::BlockUnlisted => "BL",
+        }
+    }
+}
@@ -0,0 +1,7 @@
+/// Whether a command was sent by the client or received from the server.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Enum))]
+pub enum ProtocolDirection {
+    Sent,
+    Received,
+}
@@ -0,0 +1,9 @@
+/// Supported proxy protocols.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Enum))]
+pub enum ProxyType {
+    Socks5,
+
+    /// An HTTP proxy supporting the `CONNECT` method.
+    HttpConnect,
+}
@@ -0,0 +1,17 @@
+/// The kind of server a connection is made to.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Enum))]
+pub enum ServerType {
+    NotificationServer,
+    Switchboard,
+}
+
+impl ServerType {
+    /// The name used by the HTTP gateway to open a session.
+    pub(crate) fn as_str(&self) -> &'static str {
+        match self {
+            ServerType::NotificationServer => "NS",
+            ServerType::Switchboard => "SB",
+        }
+    }
+}
@@ -0,0 +1,28 @@
+/// Pages that can be requested with [request_service_url][crate::Client::request_service_url].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Enum))]
+pub enum ServiceUrlKind {
+    /// The Hotmail inbox.
+    Inbox,
+    /// The Hotmail compose page, optionally with a recipient.
+    Compose { to: Option<String> },
+    /// The user's profile.
+    Profile,
+    /// The page for editing the user's member directory listing.
+    Person,
+    /// The page for changing mobile settings.
+    ChangeMobile,
+}
+
+impl ServiceUrlKind {
+    pub(crate) fn as_args(&self) -> String {
+        match self {
+            ServiceUrlKind::Inbox => "INBOX".to_string(),
+            ServiceUrlKind::Compose { to: Some(to) } => format!("COMPOSE {to}"),
+            ServiceUrlKind::Compose { to: None } => "COMPOSE".to_string(),
+            ServiceUrlKind::Profile => "PROFILE 0x0409".to_string(),
+            ServiceUrlKind::Person => "PERSON 0x0409".to_string(),
+            ServiceUrlKind::ChangeMobile => "CHGMOB".to_string(),
+        }
+    }
+}
@@ -0,0 +1,16 @@
+/// Part of a message's text, split by [tokenize_emoticons][crate::models::emoticons::tokenize_emoticons].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Enum))]
+pub enum TextToken {
+    Text(String),
+    /// One of Messenger's standard emoticons, with a name like `smile` or `thumbs_up`.
+    Emoticon {
+        shortcut: String,
+        name: String,
+    },
+    /// A custom emoticon declared by the sender, whose picture can be requested with the MSN object.
+    CustomEmoticon {
+        shortcut: String,
+        msn_object: String,
+    },
+}
@@ -0,0 +1,14 @@
+/// How connections to the Notification Server and Switchboards are made.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Enum))]
+pub enum Transport {
+    /// Plain TCP, usually on port 1863.
+    Tcp,
+
+    /// TCP wrapped in TLS, validated against the Mozilla root certificates.
+    Tls,
+
+    /// The MSN HTTP gateway polling protocol, for networks where port 1863 is blocked.
+    /// `gateway_url` points to the gateway itself, e.g. `http://gateway.messenger.hotmail.com/gateway/gateway.dll`.
+    HttpGateway { gateway_url: String },
+}
@@ -14,4 +14,8 @@ pub enum ContactError {
     TransmittingError,
     #[error("The contact you're trying to invite is offline")]
     ContactIsOffline,
+    #[error("The contact is already in that list")]
+    AlreadyInList,
+    #[error("There's no pending request from that contact")]
+    NoPendingRequest,
 }
@@ -10,4 +10,16 @@ pub enum MessagingError {
     CouldNotGetSessionId,
     #[error("Message could not be delivered to all recipients")]
     MessageNotDelivered,
+    #[error("Could not start a session with the contacts")]
+    CouldNotStartSession,
+    #[error("The contact you're trying to invite is offline")]
+    ContactIsOffline,
+    #[error("Message is too long to be sent")]
+    MessageTooLong,
+    #[error("Content type and headers can't contain line breaks")]
+    InvalidHeaders,
+    #[error("Message body must be UTF-8 text")]
+    InvalidBody,
+    #[error("Please log in before using this command")]
+    NotLoggedIn,
 }
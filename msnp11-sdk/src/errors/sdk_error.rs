@@ -14,6 +14,8 @@ pub enum SdkError {
     AuthenticationHeaderNotFound,
     #[error("Authentication error. Check your email and password.")]
     CouldNotGetAuthenticationString,
+    #[error("The ticket was rejected by the server")]
+    TicketRejected,
     #[error("An invalid argument was sent")]
     InvalidArgument,
     #[error("Lost connection to the server")]
@@ -24,6 +26,8 @@ pub enum SdkError {
     TransmittingError,
     #[error("Could not connect to the server")]
     CouldNotConnectToServer,
+    #[error("Could not connect through the proxy")]
+    ProxyError,
     #[error("Could not create runtime")]
     CouldNotCreateRuntime,
     #[error("Could not write user data")]
@@ -34,6 +38,10 @@ pub enum SdkError {
     InvalidContact,
     #[error("The contact you're trying to invite is offline")]
     ContactIsOffline,
+    #[error("The invited contact didn't join the session")]
+    NoAnswer,
+    #[error("The invited contact declined to join the session")]
+    InviteDeclined,
     #[error("Could not create an MSN object for the display picture")]
     CouldNotCreateMsnObject,
     #[cfg(feature = "config")]
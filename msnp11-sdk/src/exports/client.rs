@@ -2,7 +2,13 @@ use crate::enums::msnp_status::MsnpStatus;
 use crate::errors::contact_error::ContactError;
 use crate::errors::sdk_error::SdkError;
 use crate::event_handler::EventHandler;
-use crate::{Config, Event, MsnpList, PersonalMessage, Switchboard};
+use crate::protocol_log_handler::ProtocolLogHandler;
+use crate::{
+    AddNotificationMode, ChallengeKeys, ClientCapabilities, Config, ContactRequest, Conversation,
+    Event, GroupChat, MsnpList, PassportAuthenticator, PassportTicket, PersonalMessage,
+    PhoneProperty, PresenceManager, PrivacyMode, Proxy, RstAuthenticator, ServiceUrl,
+    ServiceUrlKind, Switchboard, TicketAuthenticator, Transport,
+};
 use std::sync::Arc;
 use tokio::runtime::Runtime;
 
@@ -15,7 +21,7 @@ pub struct Client {
 
 #[uniffi::export]
 impl Client {
-    /// Connects to the server, defines the channels and returns a new instance.
+    /// Connects to the server through plain TCP, defines the channels and returns a new instance.
     #[uniffi::constructor]
     pub fn new(server: &str, port: u16) -> Result<Self, SdkError> {
         let rt = Runtime::new().or(Err(SdkError::CouldNotCreateRuntime))?;
@@ -23,13 +29,58 @@ impl Client {
         Ok(Self { inner: client, rt })
     }
 
+    /// Connects to the server using the specified [Transport], defines the channels and returns a new instance.
+    /// Switchboard sessions created or answered by this client use the same transport.
+    #[uniffi::constructor]
+    pub fn new_with_transport(
+        server: &str,
+        port: u16,
+        transport: Transport,
+    ) -> Result<Self, SdkError> {
+        let rt = Runtime::new().or(Err(SdkError::CouldNotCreateRuntime))?;
+        let client = rt.block_on(async {
+            crate::client::Client::new_with_transport(server, port, transport).await
+        })?;
+
+        Ok(Self { inner: client, rt })
+    }
+
+    /// Connects to the server using the specified [Transport] through a [Proxy], defines the channels and returns a
+    /// new instance. The proxy is also used for Switchboards, direct P2P connections and Passport requests.
+    #[uniffi::constructor]
+    pub fn new_with_proxy(
+        server: &str,
+        port: u16,
+        transport: Transport,
+        proxy: Proxy,
+    ) -> Result<Self, SdkError> {
+        let rt = Runtime::new().or(Err(SdkError::CouldNotCreateRuntime))?;
+        let client = rt.block_on(async {
+            crate::client::Client::new_with_proxy(server, port, transport, proxy).await
+        })?;
+
+        Ok(Self { inner: client, rt })
+    }
+
     /// Adds a new handler that implements the [EventHandler] trait.
     pub fn add_event_handler(&self, handler: Arc<dyn EventHandler>) {
         self.rt
             .block_on(async { self.inner.add_event_handler(handler) })
     }
 
-    /// Does the MSNP authentication process. Also starts regular pings and the handler for Switchboard invitations.
+    /// Sets a handler that receives every command sent and received, including the ones of Switchboards created or
+    /// answered by this client. Credentials are redacted, so this can be enabled for debugging.
+    pub fn set_protocol_log_handler(&self, handler: Arc<dyn ProtocolLogHandler>) {
+        self.inner.set_protocol_log_handler(handler)
+    }
+
+    /// Sets the product ID and key used to answer challenges from the server. Defaults to the ones used by MSN Messenger 7.0.
+    pub async fn set_challenge_keys(&self, challenge_keys: ChallengeKeys) {
+        self.inner.set_challenge_keys(challenge_keys).await
+    }
+
+    /// Does the MSNP authentication process. Also starts regular pings and the handlers for challenges and Switchboard
+    /// invitations.
     ///
     /// # Events
     /// If the server you're connecting to implements a Dispatch Server, then this will return a [RedirectedTo][Event::RedirectedTo] event.
@@ -50,17 +101,106 @@ impl Client {
         })
     }
 
+    /// Does the MSNP authentication process through a SOAP `RequestSecurityToken` request sent to the specified URL.
+    /// Returns the same events as [login][Client::login].
+    pub async fn login_with_rst(
+        &self,
+        email: String,
+        password: String,
+        rst_url: String,
+        client_name: &str,
+        version: &str,
+    ) -> Result<Event, SdkError> {
+        let authenticator = RstAuthenticator::new(password, rst_url);
+        self.rt.block_on(async {
+            self.inner
+                .login_with_authenticator(email, &authenticator, client_name, version)
+                .await
+        })
+    }
+
+    /// Does the MSNP authentication process using a ticket obtained beforehand. Returns the same events as
+    /// [login][Client::login].
+    pub async fn login_with_ticket(
+        &self,
+        email: String,
+        ticket: String,
+        client_name: &str,
+        version: &str,
+    ) -> Result<Event, SdkError> {
+        let authenticator = TicketAuthenticator::new(ticket);
+        self.rt.block_on(async {
+            self.inner
+                .login_with_authenticator(email, &authenticator, client_name, version)
+                .await
+        })
+    }
+
+    /// Does the MSNP authentication process using a [PassportTicket] from a previous login. Passport 1.4 is only used
+    /// if there's no ticket or if it has expired. Returns the same events as [login][Client::login], or
+    /// [TicketRejected][SdkError::TicketRejected] if the server rejects the ticket, after which a new client has to log
+    /// in without it.
+    pub async fn login_with_cached_ticket(
+        &self,
+        email: String,
+        cached_ticket: Option<PassportTicket>,
+        password: String,
+        nexus_url: String,
+        client_name: &str,
+        version: &str,
+    ) -> Result<Event, SdkError> {
+        let authenticator = PassportAuthenticator::new(password, nexus_url);
+        self.rt.block_on(async {
+            self.inner
+                .login_with_cached_ticket(
+                    email,
+                    cached_ticket,
+                    &authenticator,
+                    client_name,
+                    version,
+                )
+                .await
+        })
+    }
+
+    /// Returns the ticket obtained in the last login, which can be cached and passed to
+    /// [login_with_cached_ticket][Client::login_with_cached_ticket] later.
+    pub async fn get_ticket(&self) -> Option<PassportTicket> {
+        self.inner.get_ticket().await
+    }
+
     /// Makes a request to get the config file (containing tabs and the MSN Today url) and returns it.
     pub async fn get_config(&self, config_url: &str) -> Result<Config, SdkError> {
         self.rt
             .block_on(async { self.inner.get_config(config_url).await })
     }
 
-    /// Sets the user's presence status.
+    /// Sets the user's presence status, advertising the capabilities set with
+    /// [set_capabilities][Client::set_capabilities].
     pub async fn set_presence(&self, presence: MsnpStatus) -> Result<(), SdkError> {
         self.inner.set_presence(presence).await
     }
 
+    /// Attaches a [PresenceManager] to this client, which sets the status and personal message it remembers from a
+    /// previous client and starts watching for inactivity. Should be called after logging in.
+    pub fn attach_presence_manager(
+        &self,
+        presence_manager: Arc<PresenceManager>,
+    ) -> Result<(), SdkError> {
+        self.rt
+            .block_on(async { self.inner.attach_presence_manager(&presence_manager).await })
+    }
+
+    /// Stops the attached [PresenceManager] from changing this client's status.
+    pub async fn detach_presence_manager(&self) {
+        self.inner.detach_presence_manager().await
+    }
+
+    /// Sets the features this client implements, sent the next time the presence is set. Defaults to MSNC4.
+    pub async fn set_capabilities(&self, capabilities: ClientCapabilities) {
+        self.inner.set_capabilities(capabilities).await
+    }
+
     /// Sets the user's personal message.
     pub async fn set_personal_message(
         &self,
@@ -74,6 +214,16 @@ impl Client {
         self.inner.set_display_name(display_name).await
     }
 
+    /// Sets one of the user's phone numbers or mobile settings. Setting a number to an empty string removes it.
+    pub async fn set_phone_property(&self, property: PhoneProperty) -> Result<(), SdkError> {
+        self.inner.set_phone_property(property).await
+    }
+
+    /// Sends a page to a contact's mobile phone. The contact needs to have mobile paging enabled.
+    pub async fn send_page(&self, email: &str, message: &str) -> Result<(), ContactError> {
+        self.inner.send_page(email, message).await
+    }
+
     /// Sets a contact's display name.
     pub async fn set_contact_display_name(
         &self,
@@ -106,12 +256,14 @@ impl Client {
         self.inner.remove_contact_from_forward_list(guid).await
     }
 
-    /// Blocks a contact.
+    /// Blocks a contact, moving it from the allow list to the block list. Returns
+    /// [AlreadyInList][ContactError::AlreadyInList] if the contact is already blocked.
     pub async fn block_contact(&self, email: &str) -> Result<(), ContactError> {
         self.inner.block_contact(email).await
     }
 
-    /// Unblocks a contact.
+    /// Unblocks a contact, moving it from the block list to the allow list. Returns
+    /// [AlreadyInList][ContactError::AlreadyInList] if the contact is already allowed.
     pub async fn unblock_contact(&self, email: &str) -> Result<(), ContactError> {
         self.inner.unblock_contact(email).await
     }
@@ -149,22 +301,88 @@ impl Client {
         self.inner.remove_contact_from_group(guid, group_guid).await
     }
 
-    /// Sets the GTC value, which can be either `A` or `N`.
-    pub async fn set_gtc(&self, gtc: &str) -> Result<(), SdkError> {
+    /// Sets whether to be prompted when someone adds you to their contact list.
+    pub async fn set_gtc(&self, gtc: AddNotificationMode) -> Result<(), SdkError> {
         self.inner.set_gtc(gtc).await
     }
 
-    /// Sets the GTC value, which can be either `AL` or `BL`.
-    pub async fn set_blp(&self, blp: &str) -> Result<(), SdkError> {
+    /// Requests the URLs needed to open a Hotmail or profile page already authenticated.
+    pub async fn request_service_url(&self, kind: ServiceUrlKind) -> Result<ServiceUrl, SdkError> {
+        self.inner.request_service_url(kind).await
+    }
+
+    /// Sets who can see your presence and talk to you. Does nothing if the mode is already set.
+    pub async fn set_blp(&self, blp: PrivacyMode) -> Result<(), SdkError> {
         self.inner.set_blp(blp).await
     }
 
+    /// Returns whether a contact can see your presence and talk to you, according to the privacy mode and the allow
+    /// and block lists.
+    pub async fn is_contact_allowed(&self, email: &str) -> bool {
+        self.inner.is_contact_allowed(email).await
+    }
+
+    /// Returns the people who added you to their contact list and haven't been allowed or blocked yet.
+    pub async fn get_contact_requests(&self) -> Vec<ContactRequest> {
+        self.inner.get_contact_requests().await
+    }
+
+    /// Accepts a contact request by adding the contact to the allow list, optionally also adding it to the forward
+    /// list and to a group.
+    pub async fn accept_contact_request(
+        &self,
+        email: &str,
+        add_to_forward_list: bool,
+        group_guid: Option<String>,
+    ) -> Result<(), ContactError> {
+        self.inner
+            .accept_contact_request(email, add_to_forward_list, group_guid.as_deref())
+            .await
+    }
+
+    /// Rejects a contact request, optionally adding the contact to the block list.
+    pub async fn reject_contact_request(
+        &self,
+        email: &str,
+        block: bool,
+    ) -> Result<(), ContactError> {
+        self.inner.reject_contact_request(email, block).await
+    }
+
+    /// Returns the current privacy mode, if already received from the server.
+    pub async fn get_privacy_mode(&self) -> Option<PrivacyMode> {
+        self.inner.get_privacy_mode().await
+    }
+
     /// Creates and returns a new Switchboard session with the specified contact.
     pub async fn create_session(&self, email: &str) -> Result<Switchboard, SdkError> {
         self.rt
             .block_on(async { self.inner.create_session(email).await })
     }
 
+    /// Opens a new Switchboard session for a group chat.
+    pub async fn create_group_chat(&self) -> Result<Arc<GroupChat>, SdkError> {
+        self.rt
+            .block_on(async { self.inner.create_group_chat().await })
+    }
+
+    /// Makes answered Switchboard invitations go to conversations instead of being sent as
+    /// [SessionAnswered][Event::SessionAnswered] events.
+    pub fn enable_conversations(&self) {
+        self.inner.enable_conversations()
+    }
+
+    /// Returns the conversation with the specified contacts, creating it if there isn't one yet.
+    pub async fn get_conversation(&self, participants: Vec<String>) -> Arc<Conversation> {
+        let participants: Vec<&str> = participants.iter().map(String::as_str).collect();
+        self.inner.get_conversation(&participants).await
+    }
+
+    /// Returns every conversation this client has.
+    pub async fn get_conversations(&self) -> Vec<Arc<Conversation>> {
+        self.inner.get_conversations().await
+    }
+
     /// Sets the user's display picture, returning a standard base64 encoded hash of it.
     /// This method uses the picture's binary data, and scaling down beforehand to a size like 200x200 is recommended.
     pub async fn set_display_picture(&self, display_picture: Vec<u8>) -> Result<String, SdkError> {
@@ -0,0 +1,66 @@
+use crate::errors::messaging_error::MessagingError;
+use crate::errors::sdk_error::SdkError;
+use crate::event_handler::EventHandler;
+use crate::{Conversation, GroupChat, PlainText, Switchboard};
+use std::sync::Arc;
+use tokio::runtime::Runtime;
+
+/// Wraps an obtained [Conversation] object for use outside a tokio runtime. A [Conversation] outlives
+/// Switchboard sessions, opening a new one when something is sent and the previous one was closed.
+#[derive(uniffi::Object)]
+pub struct ConversationWrapper {
+    inner: Arc<Conversation>,
+    rt: Runtime,
+}
+
+#[uniffi::export]
+impl ConversationWrapper {
+    /// Create new wrapper instance with an obtained [Conversation].
+    #[uniffi::constructor]
+    pub fn new(conversation: Arc<Conversation>) -> Result<Self, SdkError> {
+        let rt = Runtime::new().or(Err(SdkError::CouldNotCreateRuntime))?;
+        Ok(Self {
+            inner: conversation,
+            rt,
+        })
+    }
+
+    /// Adds a new handler that implements the [EventHandler] trait.
+    pub fn add_event_handler(&self, handler: Arc<dyn EventHandler>) {
+        self.rt
+            .block_on(async { self.inner.add_event_handler(handler) })
+    }
+
+    /// Returns the contacts this conversation is with.
+    pub fn get_participants(&self) -> Vec<String> {
+        self.inner.get_participants()
+    }
+
+    /// Returns the current Switchboard session, if one was opened and is still connected.
+    pub async fn get_switchboard(&self) -> Option<Arc<Switchboard>> {
+        self.inner.get_switchboard().await
+    }
+
+    /// Sends a plain text message, opening a session first if needed.
+    pub async fn send_text_message(&self, message: &PlainText) -> Result<(), MessagingError> {
+        self.rt
+            .block_on(async { self.inner.send_text_message(message).await })
+    }
+
+    /// Sends a nudge, opening a session first if needed.
+    pub async fn send_nudge(&self) -> Result<(), MessagingError> {
+        self.rt.block_on(async { self.inner.send_nudge().await })
+    }
+
+    /// Moves the conversation's session to a [GroupChat] other contacts can be invited to. The conversation opens a
+    /// new session the next time something is sent.
+    pub async fn convert_to_group(&self) -> Result<Arc<GroupChat>, MessagingError> {
+        self.rt
+            .block_on(async { self.inner.convert_to_group().await })
+    }
+
+    /// Tells the contacts the user is writing if there's a session open.
+    pub async fn notify_typing(&self) -> Result<(), MessagingError> {
+        self.inner.notify_typing().await
+    }
+}
@@ -0,0 +1,68 @@
+use crate::errors::messaging_error::MessagingError;
+use crate::errors::sdk_error::SdkError;
+use crate::event_handler::EventHandler;
+use crate::{GroupChat, InviteResult, PlainText, Switchboard};
+use std::sync::Arc;
+use tokio::runtime::Runtime;
+
+/// Wraps an obtained [GroupChat] object for use outside a tokio runtime.
+#[derive(uniffi::Object)]
+pub struct GroupChatWrapper {
+    inner: Arc<GroupChat>,
+    rt: Runtime,
+}
+
+#[uniffi::export]
+impl GroupChatWrapper {
+    /// Create new wrapper instance with an obtained [GroupChat].
+    #[uniffi::constructor]
+    pub fn new(group_chat: Arc<GroupChat>) -> Result<Self, SdkError> {
+        let rt = Runtime::new().or(Err(SdkError::CouldNotCreateRuntime))?;
+        Ok(Self {
+            inner: group_chat,
+            rt,
+        })
+    }
+
+    /// Adds a new handler that implements the [EventHandler] trait.
+    pub fn add_event_handler(&self, handler: Arc<dyn EventHandler>) {
+        self.rt
+            .block_on(async { self.inner.add_event_handler(handler) })
+    }
+
+    /// Returns the contacts currently in the chat.
+    pub async fn get_members(&self) -> Vec<String> {
+        self.inner.get_members().await
+    }
+
+    /// Returns whether more than one contact is in the chat.
+    pub async fn is_group(&self) -> bool {
+        self.inner.is_group().await
+    }
+
+    /// Returns the Switchboard session used by the chat.
+    pub fn get_switchboard(&self) -> Arc<Switchboard> {
+        self.inner.get_switchboard()
+    }
+
+    /// Invites every contact at the same time, returning a result for each in the same order.
+    pub async fn invite(&self, emails: Vec<String>) -> Vec<InviteResult> {
+        let emails: Vec<&str> = emails.iter().map(String::as_str).collect();
+        self.rt.block_on(async { self.inner.invite(&emails).await })
+    }
+
+    /// Sends a plain text message to everyone in the chat.
+    pub async fn send_text_message(&self, message: &PlainText) -> Result<(), MessagingError> {
+        self.inner.send_text_message(message).await
+    }
+
+    /// Sends a nudge to everyone in the chat.
+    pub async fn send_nudge(&self) -> Result<(), MessagingError> {
+        self.inner.send_nudge().await
+    }
+
+    /// Leaves the chat, disconnecting from its session.
+    pub async fn leave(&self) -> Result<(), SdkError> {
+        self.inner.leave().await
+    }
+}
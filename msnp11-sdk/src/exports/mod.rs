@@ -1,2 +1,4 @@
 mod client;
+mod conversation_wrapper;
+mod group_chat_wrapper;
 mod switchboard_wrapper;
@@ -3,7 +3,7 @@ use crate::errors::p2p_error::P2pError;
 use crate::errors::sdk_error::SdkError;
 use crate::event_handler::EventHandler;
 use crate::models::file_transfer_request::FileTransferRequest;
-use crate::{PlainText, Switchboard};
+use crate::{AckMode, MessageHandle, MimeHeader, Participant, PlainText, Switchboard};
 use std::sync::Arc;
 use tokio::runtime::Runtime;
 
@@ -40,6 +40,26 @@ impl SwitchboardWrapper {
         self.inner.invite(email).await
     }
 
+    /// Returns the contacts currently in the session.
+    pub async fn get_participants(&self) -> Vec<String> {
+        self.inner.get_participants().await
+    }
+
+    /// Returns the contacts currently in the session, with their display names and capabilities.
+    pub async fn get_roster(&self) -> Vec<Participant> {
+        self.inner.get_roster().await
+    }
+
+    /// Returns a contact in the session by email.
+    pub async fn get_participant(&self, email: &str) -> Option<Participant> {
+        self.inner.get_participant(email).await
+    }
+
+    /// Returns whether the connection to the Switchboard is still open.
+    pub fn is_connected(&self) -> bool {
+        self.inner.is_connected()
+    }
+
     /// Returns the session ID, if defined.
     pub async fn get_session_id(&self) -> Result<String, MessagingError> {
         self.inner.get_session_id().await
@@ -50,16 +70,64 @@ impl SwitchboardWrapper {
         self.inner.send_text_message(message).await
     }
 
+    /// Sends a plain text message to the session with the specified acknowledgement mode.
+    pub async fn send_text_message_with_ack(
+        &self,
+        message: &PlainText,
+        ack_mode: AckMode,
+    ) -> Result<(), MessagingError> {
+        self.inner
+            .send_text_message_with_ack(message, ack_mode)
+            .await
+    }
+
+    /// Sends a plain text message to the session without waiting for its acknowledgement.
+    pub async fn queue_text_message(
+        &self,
+        message: &PlainText,
+        ack_mode: AckMode,
+    ) -> Result<MessageHandle, MessagingError> {
+        self.inner.queue_text_message(message, ack_mode).await
+    }
+
     /// Sends a nudge to the session.
     pub async fn send_nudge(&self) -> Result<(), MessagingError> {
         self.inner.send_nudge().await
     }
 
+    /// Sends a nudge to the session with the specified acknowledgement mode.
+    pub async fn send_nudge_with_ack(&self, ack_mode: AckMode) -> Result<(), MessagingError> {
+        self.inner.send_nudge_with_ack(ack_mode).await
+    }
+
+    /// Sends a nudge to the session without waiting for its acknowledgement.
+    pub async fn queue_nudge(&self, ack_mode: AckMode) -> Result<MessageHandle, MessagingError> {
+        self.inner.queue_nudge(ack_mode).await
+    }
+
     /// Sends an "is writing..." notification to the session.
     pub async fn send_typing_user(&self, email: &str) -> Result<(), MessagingError> {
         self.inner.send_typing_user(email).await
     }
 
+    /// Tells the session the user is writing, sending a notification at most every 5 seconds.
+    pub async fn notify_typing(&self) -> Result<(), MessagingError> {
+        self.inner.notify_typing().await
+    }
+
+    /// Sends a message with any content type, for extensions the SDK doesn't implement.
+    pub async fn send_raw_message(
+        &self,
+        content_type: &str,
+        headers: Vec<MimeHeader>,
+        body: Vec<u8>,
+        ack_mode: AckMode,
+    ) -> Result<(), MessagingError> {
+        self.inner
+            .send_raw_message(content_type, &headers, &body, ack_mode)
+            .await
+    }
+
     /// Requests a contact's display picture and handles the transfer process. A [DisplayPicture][Event::DisplayPicture] event
     /// is received once the transfer is complete.
     pub async fn request_contact_display_picture(
@@ -0,0 +1,208 @@
+use crate::enums::event::Event;
+use crate::enums::invite_status::InviteStatus;
+use crate::errors::messaging_error::MessagingError;
+use crate::errors::sdk_error::SdkError;
+#[cfg(feature = "uniffi")]
+use crate::event_handler::EventHandler;
+use crate::models::invite_result::InviteResult;
+use crate::models::plain_text::PlainText;
+use crate::switchboard_server::event_fanout::EventFanout;
+use crate::switchboard_server::switchboard::Switchboard;
+use log::error;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+use tokio::task::JoinSet;
+
+/// A Switchboard session with several contacts. Contacts are invited concurrently, with a result for each, and joins
+/// and leaves are tracked, sending [GroupChatBecameOneToOne][Event::GroupChatBecameOneToOne] when only one contact is
+/// left.
+#[derive(Debug)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Object))]
+pub struct GroupChat {
+    switchboard: Arc<Switchboard>,
+    members: Arc<Mutex<Vec<String>>>,
+    events: Arc<EventFanout>,
+}
+
+impl GroupChat {
+    /// Uses a session, keeping the contacts already in it.
+    pub(crate) async fn new(switchboard: Arc<Switchboard>) -> Self {
+        let events = Arc::new(EventFanout::default());
+        let members = Arc::new(Mutex::new(switchboard.get_participants().await));
+
+        let task_members = members.clone();
+        let task_events = events.clone();
+        let switchboard_rx = switchboard.event_receiver();
+
+        tokio::spawn(async move {
+            let mut was_group = task_members.lock().await.len() > 1;
+            while let Ok(event) = switchboard_rx.recv().await {
+                let mut one_to_one = None;
+                match &event {
+                    Event::ParticipantInSwitchboard { email, .. } => {
+                        let mut members = task_members.lock().await;
+                        if !members
+                            .iter()
+                            .any(|member| member.eq_ignore_ascii_case(email))
+                        {
+                            members.push(email.clone());
+                        }
+
+                        was_group |= members.len() > 1;
+                    }
+
+                    Event::ParticipantLeftSwitchboard { email } => {
+                        let mut members = task_members.lock().await;
+                        members.retain(|member| !member.eq_ignore_ascii_case(email));
+
+                        if was_group && members.len() == 1 {
+                            was_group = false;
+                            one_to_one = Some(Event::GroupChatBecameOneToOne {
+                                email: members[0].clone(),
+                            });
+                        }
+                    }
+
+                    _ => (),
+                }
+
+                for event in std::iter::once(event).chain(one_to_one) {
+                    if let Err(error) = task_events.send(event) {
+                        error!("{error}");
+                    }
+                }
+            }
+
+            task_events.close();
+        });
+
+        Self {
+            switchboard,
+            members,
+            events,
+        }
+    }
+
+    /// Adds a handler closure. If you're using this SDK with Rust, not through a foreign binding, then this is the preferred method of
+    /// handling events.
+    pub fn add_event_handler_closure<F, R>(&self, f: F)
+    where
+        F: Fn(Event) -> R + Send + 'static,
+        R: Future<Output = ()> + Send,
+    {
+        let event_rx = self.events.subscribe();
+        tokio::spawn(async move {
+            while let Ok(event) = event_rx.recv().await {
+                f(event).await;
+            }
+        });
+    }
+
+    #[cfg(feature = "uniffi")]
+    /// Adds a new handler that implements the [EventHandler] trait.
+    ///
+    /// This exists for the foreign language bindings, with which generics don't
+    /// work. Prefer [`add_event_handler_closure`][GroupChat::add_event_handler_closure] if using this SDK with Rust.
+    pub fn add_event_handler(&self, handler: Arc<dyn EventHandler>) {
+        let event_rx = self.events.subscribe();
+        tokio::spawn(async move {
+            while let Ok(event) = event_rx.recv().await {
+                handler.handle(event).await;
+            }
+        });
+    }
+
+    /// Returns the contacts currently in the chat.
+    pub async fn get_members(&self) -> Vec<String> {
+        self.members.lock().await.clone()
+    }
+
+    /// Returns whether more than one contact is in the chat.
+    pub async fn is_group(&self) -> bool {
+        self.members.lock().await.len() > 1
+    }
+
+    /// Returns the Switchboard session used by the chat.
+    pub fn get_switchboard(&self) -> Arc<Switchboard> {
+        self.switchboard.clone()
+    }
+
+    /// Invites every contact at the same time and waits for each one to join or fail, returning the results in the
+    /// same order as the emails.
+    pub async fn invite(&self, emails: &[&str]) -> Vec<InviteResult> {
+        let members = self.members.lock().await.clone();
+        let mut invites = JoinSet::new();
+
+        for (index, email) in emails.iter().enumerate() {
+            let email = email.to_string();
+            let already_in_session = members
+                .iter()
+                .any(|member| member.eq_ignore_ascii_case(&email));
+
+            let switchboard = self.switchboard.clone();
+            let members = self.members.clone();
+
+            invites.spawn(async move {
+                let status = if already_in_session {
+                    InviteStatus::AlreadyInSession
+                } else {
+                    match switchboard.invite_and_wait(&email).await {
+                        Ok(()) => {
+                            // The join event might not have been handled yet
+                            let mut members = members.lock().await;
+                            if !members
+                                .iter()
+                                .any(|member| member.eq_ignore_ascii_case(&email))
+                            {
+                                members.push(email.clone());
+                            }
+
+                            InviteStatus::Joined
+                        }
+
+                        Err(SdkError::ContactIsOffline) => InviteStatus::Offline,
+                        Err(SdkError::InvalidContact) => InviteStatus::InvalidContact,
+                        Err(SdkError::NoAnswer) => InviteStatus::NoAnswer,
+                        Err(SdkError::InviteDeclined) => InviteStatus::Declined,
+                        Err(_) => InviteStatus::Failed,
+                    }
+                };
+
+                (index, InviteResult { email, status })
+            });
+        }
+
+        // An invite whose task panicked keeps its failed result
+        let mut results: Vec<InviteResult> = emails
+            .iter()
+            .map(|email| InviteResult {
+                email: email.to_string(),
+                status: InviteStatus::Failed,
+            })
+            .collect();
+
+        while let Some(result) = invites.join_next().await {
+            match result {
+                Ok((index, result)) => results[index] = result,
+                Err(error) => error!("{error}"),
+            }
+        }
+
+        results
+    }
+
+    /// Sends a plain text message to everyone in the chat.
+    pub async fn send_text_message(&self, message: &PlainText) -> Result<(), MessagingError> {
+        self.switchboard.send_text_message(message).await
+    }
+
+    /// Sends a nudge to everyone in the chat.
+    pub async fn send_nudge(&self) -> Result<(), MessagingError> {
+        self.switchboard.send_nudge().await
+    }
+
+    /// Leaves the chat, disconnecting from its session.
+    pub async fn leave(&self) -> Result<(), SdkError> {
+        self.switchboard.disconnect().await
+    }
+}
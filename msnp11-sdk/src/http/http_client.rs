@@ -1,13 +1,12 @@
-use crate::errors::sdk_error::SdkError;
 #[cfg(feature = "config")]
 use crate::http::config::Config;
 #[cfg(feature = "config")]
 use crate::http::xml::envelope::SoapEnvelope;
 #[cfg(feature = "config")]
 use crate::http::xml::msgr_config::MsgrConfig;
-use reqwest::header::{AUTHORIZATION, HeaderMap};
 #[cfg(feature = "config")]
-use reqwest::header::{CONTENT_TYPE, HeaderValue};
+use reqwest::header::{CONTENT_TYPE, HeaderMap, HeaderValue};
+#[cfg(feature = "config")]
 use std::error::Error;
 
 pub struct HttpClient {
@@ -15,66 +14,13 @@ pub struct HttpClient {
 }
 
 impl HttpClient {
-    pub fn new() -> Self {
-        Self {
-            client: reqwest::Client::new(),
-        }
-    }
-
-    async fn get_login_srf(&self, nexus_url: &str) -> Result<String, Box<dyn Error>> {
-        let response = self.client.get(nexus_url).send().await?;
-        let mut url = response
-            .headers()
-            .get("Passporturls")
-            .ok_or(SdkError::AuthenticationHeaderNotFound)?
-            .to_str()?
-            .replace("DALogin=", "");
-
-        if !url.starts_with("http") {
-            url.insert_str(0, "https://");
-        }
-
-        Ok(url)
+    pub fn new(client: reqwest::Client) -> Self {
+        Self { client }
     }
 
-    pub async fn get_passport_token(
-        &self,
-        email: &str,
-        password: &str,
-        nexus_url: &str,
-        authorization_string: &str,
-    ) -> Result<String, SdkError> {
-        let login_srf = self
-            .get_login_srf(nexus_url)
-            .await
-            .or(Err(SdkError::CouldNotGetAuthenticationString))?;
-
-        let mut headers = HeaderMap::with_capacity(1);
-        headers.insert(AUTHORIZATION, format!("Passport1.4 OrgVerb=GET,OrgURL=http%3A%2F%2Fmessenger%2Emsn%2Ecom,sign-in={email},pwd={password},{authorization_string}").parse().or(Err(SdkError::CouldNotGetAuthenticationString))?);
-
-        let response = self
-            .client
-            .get(login_srf)
-            .headers(headers)
-            .send()
-            .await
-            .or(Err(SdkError::ReceivingError))?;
-
-        let authentication_info = response
-            .headers()
-            .get("Authentication-Info")
-            .ok_or(SdkError::AuthenticationHeaderNotFound)?
-            .to_str()
-            .or(Err(SdkError::CouldNotGetAuthenticationString))?;
-
-        let mut token = authentication_info.split("from-PP='");
-        token.next();
-
-        let token = token
-            .next()
-            .ok_or(SdkError::CouldNotGetAuthenticationString)?;
-
-        Ok(token.replace("'", ""))
+    /// The underlying client, which sends requests through the proxy if there's one.
+    pub fn client(&self) -> &reqwest::Client {
+        &self.client
     }
 
     #[cfg(feature = "config")]
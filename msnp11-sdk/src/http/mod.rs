@@ -1,5 +1,4 @@
 #[cfg(feature = "config")]
 pub mod config;
 pub mod http_client;
-#[cfg(feature = "config")]
 pub mod xml;
@@ -1,3 +1,7 @@
+#[cfg(feature = "config")]
 pub mod envelope;
+#[cfg(feature = "config")]
 pub mod msgr_config;
+pub mod rst_envelope;
+#[cfg(feature = "config")]
 pub mod tab;
@@ -0,0 +1,59 @@
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+pub(crate) struct RstEnvelope {
+    #[serde(rename = "Body")]
+    pub body: RstBody,
+}
+
+#[derive(Deserialize)]
+pub(crate) struct RstBody {
+    #[serde(rename = "RequestSecurityTokenResponseCollection")]
+    pub collection: RequestSecurityTokenResponseCollection,
+}
+
+#[derive(Deserialize)]
+pub(crate) struct RequestSecurityTokenResponseCollection {
+    #[serde(rename = "RequestSecurityTokenResponse", default)]
+    pub responses: Vec<RequestSecurityTokenResponse>,
+}
+
+#[derive(Deserialize)]
+pub(crate) struct RequestSecurityTokenResponse {
+    #[serde(rename = "AppliesTo")]
+    pub applies_to: AppliesTo,
+    #[serde(rename = "RequestedSecurityToken")]
+    pub requested_security_token: RequestedSecurityToken,
+    #[serde(rename = "Lifetime")]
+    pub lifetime: Option<Lifetime>,
+}
+
+#[derive(Deserialize)]
+pub(crate) struct Lifetime {
+    #[serde(rename = "Expires")]
+    pub expires: Option<String>,
+}
+
+#[derive(Deserialize)]
+pub(crate) struct AppliesTo {
+    #[serde(rename = "EndpointReference")]
+    pub endpoint_reference: EndpointReference,
+}
+
+#[derive(Deserialize)]
+pub(crate) struct EndpointReference {
+    #[serde(rename = "Address")]
+    pub address: String,
+}
+
+#[derive(Deserialize)]
+pub(crate) struct RequestedSecurityToken {
+    #[serde(rename = "BinarySecurityToken")]
+    pub binary_security_token: Option<BinarySecurityToken>,
+}
+
+#[derive(Deserialize)]
+pub(crate) struct BinarySecurityToken {
+    #[serde(rename = "$text")]
+    pub value: String,
+}
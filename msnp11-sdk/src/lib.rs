@@ -1,11 +1,12 @@
 //! An MSNP11 client SDK.
 //! # Login
-//! ```
+//! ```no_run
 //! use msnp11_sdk::client::Client;
 //! use msnp11_sdk::enums::event::Event;
 //! use msnp11_sdk::models::personal_message::PersonalMessage;
 //! use msnp11_sdk::enums::msnp_status::MsnpStatus;
 //!
+//! # async fn example() {
 //! let mut client = Client::new("127.0.0.1", 1863)
 //!    .await
 //!    .unwrap();
@@ -40,44 +41,88 @@
 //! client
 //!     .set_personal_message(&PersonalMessage {
 //!         psm: "test".to_string(),
-//!         current_media: "".to_string(),
+//!         current_media: None,
 //!     })
 //!     .await
 //!     .unwrap();
+//! # }
 //! ```
 //! # Bindings
 //! Bindings for Kotlin and Swift can be generated with
 //! [UniFFI](https://mozilla.github.io/uniffi-rs/latest/tutorial/foreign_language_bindings.html#multi-crate-workspaces).
 //!
 
+pub mod auth;
 pub mod client;
+pub mod conversation;
 pub mod enums;
 mod errors;
 #[cfg(feature = "uniffi")]
 pub mod event_handler;
 #[cfg(feature = "uniffi")]
 mod exports;
+pub mod group_chat;
 mod http;
+mod mime;
 pub mod models;
 mod notification_server;
+pub mod presence_manager;
+mod protocol_log;
+#[cfg(feature = "uniffi")]
+pub mod protocol_log_handler;
 mod receive_split;
 pub mod switchboard_server;
+mod transport;
 
 #[cfg(feature = "uniffi")]
 uniffi::setup_scaffolding!();
 
+pub use auth::authenticator::Authenticator;
+pub use auth::passport_authenticator::PassportAuthenticator;
+pub use auth::rst_authenticator::RstAuthenticator;
+pub use auth::ticket_authenticator::TicketAuthenticator;
 pub use client::Client;
+pub use conversation::Conversation;
+pub use enums::ack_mode::AckMode;
+pub use enums::add_notification_mode::AddNotificationMode;
+pub use enums::current_media_type::CurrentMediaType;
 pub use enums::event::Event;
+pub use enums::invite_status::InviteStatus;
 pub use enums::msnp_list::MsnpList;
 pub use enums::msnp_status::MsnpStatus;
+pub use enums::phone_property::PhoneProperty;
+pub use enums::privacy_mode::PrivacyMode;
+pub use enums::protocol_direction::ProtocolDirection;
+pub use enums::proxy_type::ProxyType;
+pub use enums::server_type::ServerType;
+pub use enums::service_url_kind::ServiceUrlKind;
+pub use enums::text_token::TextToken;
+pub use enums::transport::Transport;
 pub use errors::contact_error::ContactError;
 pub use errors::messaging_error::MessagingError;
 pub use errors::p2p_error::P2pError;
 pub use errors::sdk_error::SdkError;
+pub use group_chat::GroupChat;
+pub use models::auto_away_settings::AutoAwaySettings;
+pub use models::challenge_keys::ChallengeKeys;
+pub use models::client_capabilities::ClientCapabilities;
+pub use models::contact_request::ContactRequest;
+pub use models::current_media::CurrentMedia;
+pub use models::custom_emoticon::CustomEmoticon;
+pub use models::invite_result::InviteResult;
+pub use models::message_handle::MessageHandle;
+pub use models::mime_header::MimeHeader;
 pub use models::msn_object::MsnObject;
+pub use models::participant::Participant;
+pub use models::passport_ticket::PassportTicket;
 pub use models::personal_message::PersonalMessage;
 pub use models::plain_text::PlainText;
 pub use models::presence::Presence;
+pub use models::profile::Profile;
+pub use models::protocol_log_entry::ProtocolLogEntry;
+pub use models::proxy::Proxy;
+pub use models::service_url::ServiceUrl;
+pub use presence_manager::PresenceManager;
 pub use switchboard_server::switchboard::Switchboard;
 
 #[cfg(feature = "config")]
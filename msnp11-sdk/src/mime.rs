@@ -0,0 +1,50 @@
+use crate::models::mime_header::MimeHeader;
+
+/// The headers and body of a MIME payload, like the ones sent through MSG.
+#[derive(Debug)]
+pub(crate) struct MimeMessage<'a> {
+    headers: Vec<(&'a str, &'a str)>,
+    pub(crate) body: &'a str,
+}
+
+impl<'a> MimeMessage<'a> {
+    pub(crate) fn parse(payload: &'a str) -> Self {
+        let (headers, body) = payload.split_once("\r\n\r\n").unwrap_or((payload, ""));
+        let headers = headers
+            .lines()
+            .filter_map(|line| line.split_once(':'))
+            .map(|(name, value)| (name.trim(), value.trim()))
+            .collect();
+
+        Self { headers, body }
+    }
+
+    /// Returns the value of a header. Names are case insensitive and headers can be in any order.
+    pub(crate) fn header(&self, name: &str) -> Option<&'a str> {
+        self.headers
+            .iter()
+            .find(|(header, _)| header.eq_ignore_ascii_case(name))
+            .map(|(_, value)| *value)
+    }
+
+    /// Returns every header, except the ones with the specified names.
+    pub(crate) fn headers_except(&self, names: &[&str]) -> Vec<MimeHeader> {
+        self.headers
+            .iter()
+            .filter(|(header, _)| !names.iter().any(|name| header.eq_ignore_ascii_case(name)))
+            .map(|(name, value)| MimeHeader {
+                name: name.to_string(),
+                value: value.to_string(),
+            })
+            .collect()
+    }
+
+    /// Returns the header lines, except the ones with the specified names.
+    pub(crate) fn headers_without(&self, names: &[&str]) -> String {
+        self.headers_except(names)
+            .iter()
+            .map(|header| format!("{}: {}", header.name, header.value))
+            .collect::<Vec<String>>()
+            .join("\r\n")
+    }
+}
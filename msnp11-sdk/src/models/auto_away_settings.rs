@@ -0,0 +1,14 @@
+use crate::models::personal_message::PersonalMessage;
+use std::time::Duration;
+
+/// When the [PresenceManager][crate::presence_manager::PresenceManager] switches to `Idle` and `Away`.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct AutoAwaySettings {
+    /// Time without activity before switching to `Idle`.
+    pub idle_after: Duration,
+    /// Time without activity before switching to `Away`, if at all.
+    pub away_after: Option<Duration>,
+    /// Personal message set while idle or away, restored to the previous one on activity.
+    pub away_message: Option<PersonalMessage>,
+}
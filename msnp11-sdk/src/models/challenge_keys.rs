@@ -0,0 +1,16 @@
+/// The product ID and key used to answer the challenges sent by the server. Defaults to the ones used by MSN Messenger 7.0.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct ChallengeKeys {
+    pub product_id: String,
+    pub product_key: String,
+}
+
+impl Default for ChallengeKeys {
+    fn default() -> Self {
+        Self {
+            product_id: "PROD0090YUAUV{2B".to_string(),
+            product_key: "YMM8C_H7KCQ2S_KL".to_string(),
+        }
+    }
+}
@@ -0,0 +1,59 @@
+bitflags::bitflags! {
+    /// Features supported by a client, sent as the client ID when setting a presence. The highest 4 bits hold the
+    /// MSNC (P2P protocol) version, a number read and set with [msnc_version][ClientCapabilities::msnc_version] and
+    /// [with_msnc_version][ClientCapabilities::with_msnc_version].
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub struct ClientCapabilities: u32 {
+        const WINDOWS_MOBILE = 0x1;
+        const INK_GIF = 0x4;
+        const INK_ISF = 0x8;
+        const WEBCAM = 0x10;
+        const MULTI_PACKET = 0x20;
+        const MSN_MOBILE = 0x40;
+        const MSN_DIRECT = 0x80;
+        const WEB_MESSENGER = 0x200;
+        const DIRECT_IM = 0x4000;
+        const WINKS = 0x8000;
+        const SHARED_SEARCH = 0x10000;
+        const VOICE_CLIPS = 0x40000;
+        const SECURE_CHANNEL = 0x80000;
+        const SIP_INVITE = 0x100000;
+        const SHARED_FOLDERS = 0x400000;
+
+        // The MSNC version, kept by every operation but not a flag
+        const _ = 0xf0000000;
+    }
+}
+
+impl ClientCapabilities {
+    const MSNC_MASK: u32 = 0xf0000000;
+
+    /// Returns the MSNC version, 0 if P2P isn't supported.
+    pub fn msnc_version(&self) -> u8 {
+        ((self.bits() & Self::MSNC_MASK) >> 28) as u8
+    }
+
+    /// Returns the same capabilities with another MSNC version.
+    pub fn with_msnc_version(&self, version: u8) -> Self {
+        Self::from_bits_retain(
+            (self.bits() & !Self::MSNC_MASK) | (((version as u32) << 28) & Self::MSNC_MASK),
+        )
+    }
+
+    /// Whether the client supports P2P sessions, used for display pictures and file transfers.
+    pub fn supports_p2p(&self) -> bool {
+        self.msnc_version() > 0
+    }
+}
+
+impl Default for ClientCapabilities {
+    fn default() -> Self {
+        Self::empty().with_msnc_version(4)
+    }
+}
+
+#[cfg(feature = "uniffi")]
+uniffi::custom_type!(ClientCapabilities, u32, {
+    lower: |capabilities| capabilities.bits(),
+    try_lift: |bits| Ok(ClientCapabilities::from_bits_retain(bits)),
+});
@@ -0,0 +1,7 @@
+/// Someone who added you to their contact list and is waiting for you to allow or block them.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct ContactRequest {
+    pub email: String,
+    pub display_name: String,
+}
@@ -0,0 +1,92 @@
+use crate::enums::current_media_type::CurrentMediaType;
+
+const SEPARATOR: &str = "\\0";
+
+/// What the user is listening to or doing, shown along with the personal message. Sent as a string of values
+/// separated by a literal `\0`, e.g. `\0Music\01\0{0} - {1}\0Title\0Artist\0Album\0\0`.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct CurrentMedia {
+    /// Application sending the media, usually empty.
+    pub app: String,
+    pub media_type: CurrentMediaType,
+    /// Whether the media should be shown.
+    pub enabled: bool,
+    /// How to display the media, with `{0}`, `{1}`... replaced by the fields.
+    pub format: String,
+    pub fields: Vec<String>,
+}
+
+impl CurrentMedia {
+    /// Parses the format used in `UBX` and `UUX`. Returns `None` if the string is empty or incomplete.
+    pub fn decode(current_media: &str) -> Option<Self> {
+        let current_media = current_media
+            .strip_suffix(SEPARATOR)
+            .unwrap_or(current_media);
+
+        let mut values = current_media.split(SEPARATOR);
+        let app = values.next()?.to_string();
+        let media_type = CurrentMediaType::from_str(values.next()?);
+        let enabled = values.next()? == "1";
+        let format = values.next()?.to_string();
+
+        Some(Self {
+            app,
+            media_type,
+            enabled,
+            format,
+            fields: values.map(|field| field.to_string()).collect(),
+        })
+    }
+
+    /// Returns the format used in `UBX` and `UUX`.
+    pub fn encode(&self) -> String {
+        let mut current_media = [
+            self.app.as_str(),
+            self.media_type.as_str(),
+            if self.enabled { "1" } else { "0" },
+            self.format.as_str(),
+        ]
+        .join(SEPARATOR);
+
+        for field in &self.fields {
+            current_media.push_str(SEPARATOR);
+            current_media.push_str(field);
+        }
+
+        current_media + SEPARATOR
+    }
+
+    /// Returns the text to display, e.g. `Title - Artist`.
+    pub fn to_display_string(&self) -> String {
+        let mut text = self.format.clone();
+        for (index, field) in self.fields.iter().enumerate() {
+            text = text.replace(&format!("{{{index}}}"), field);
+        }
+
+        text
+    }
+}
+
+/// Serializes an optional [CurrentMedia] as its encoded string, empty if not set.
+pub(crate) mod current_media_format {
+    use super::CurrentMedia;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub(crate) fn serialize<S: Serializer>(
+        current_media: &Option<CurrentMedia>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match current_media {
+            Some(current_media) => serializer.serialize_str(&current_media.encode()),
+            None => serializer.serialize_str(""),
+        }
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<CurrentMedia>, D::Error> {
+        let current_media = String::deserialize(deserializer)?;
+        Ok(CurrentMedia::decode(&current_media))
+    }
+}
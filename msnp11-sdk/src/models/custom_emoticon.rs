@@ -0,0 +1,7 @@
+/// A custom emoticon shortcut declared by a contact before sending a message with it.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct CustomEmoticon {
+    pub shortcut: String,
+    pub msn_object: String,
+}
@@ -0,0 +1,177 @@
+//! Splitting of message text into text and emoticons, like MSN clients render it.
+
+use crate::enums::text_token::TextToken;
+use crate::models::custom_emoticon::CustomEmoticon;
+use crate::models::plain_text::PlainText;
+
+/// Shortcuts of the emoticons included with Messenger 7.x, matched ignoring case.
+pub const STANDARD_EMOTICONS: &[(&str, &str)] = &[
+    (":)", "smile"),
+    (":-)", "smile"),
+    (":D", "open_mouthed"),
+    (":-D", "open_mouthed"),
+    (";)", "wink"),
+    (";-)", "wink"),
+    (":O", "surprised"),
+    (":-O", "surprised"),
+    (":P", "tongue_out"),
+    (":-P", "tongue_out"),
+    ("(H)", "hot"),
+    (":@", "angry"),
+    (":-@", "angry"),
+    (":S", "confused"),
+    (":-S", "confused"),
+    (":$", "embarrassed"),
+    (":-$", "embarrassed"),
+    (":(", "sad"),
+    (":-(", "sad"),
+    (":'(", "crying"),
+    (":|", "disappointed"),
+    (":-|", "disappointed"),
+    ("(6)", "devil"),
+    ("(A)", "angel"),
+    ("(L)", "red_heart"),
+    ("(U)", "broken_heart"),
+    ("(M)", "messenger"),
+    ("(@)", "cat"),
+    ("(&)", "dog"),
+    ("(S)", "sleeping_moon"),
+    ("(*)", "star"),
+    ("(~)", "filmstrip"),
+    ("(8)", "note"),
+    ("(E)", "email"),
+    ("(F)", "rose"),
+    ("(W)", "wilted_rose"),
+    ("(O)", "clock"),
+    ("(K)", "kiss"),
+    ("(G)", "gift"),
+    ("(^)", "birthday_cake"),
+    ("(P)", "camera"),
+    ("(I)", "light_bulb"),
+    ("(C)", "coffee"),
+    ("(T)", "telephone"),
+    ("({)", "left_hug"),
+    ("(})", "right_hug"),
+    ("(B)", "beer"),
+    ("(D)", "martini"),
+    ("(Z)", "boy"),
+    ("(X)", "girl"),
+    ("(Y)", "thumbs_up"),
+    ("(N)", "thumbs_down"),
+    (":[", "vampire_bat"),
+    (":-[", "vampire_bat"),
+    ("(nnh)", "goat"),
+    ("(#)", "sun"),
+    ("(R)", "rainbow"),
+    (":-#", "dont_tell_anyone"),
+    ("8o|", "baring_teeth"),
+    ("8-|", "nerd"),
+    ("^o)", "sarcastic"),
+    (":-*", "secret_telling"),
+    ("+o(", "sick"),
+    ("(sn)", "snail"),
+    ("(tu)", "turtle"),
+    ("(pl)", "plate"),
+    ("(||)", "bowl"),
+    ("(pi)", "pizza"),
+    ("(so)", "soccer_ball"),
+    ("(au)", "auto"),
+    ("(ap)", "airplane"),
+    ("(um)", "umbrella"),
+    ("(ip)", "island"),
+    ("(co)", "computer"),
+    ("(mp)", "mobile_phone"),
+    ("(brb)", "be_right_back"),
+    ("(st)", "stormy_cloud"),
+    ("(h5)", "high_five"),
+    ("(mo)", "money"),
+    ("(bah)", "black_sheep"),
+    (":^)", "i_dont_know"),
+    ("*-)", "thinking"),
+    ("(li)", "lightning"),
+    ("<:o)", "party"),
+    ("8-)", "eye_rolling"),
+    ("|-)", "sleepy"),
+    ("(xx)", "xbox"),
+    ("(yn)", "fingers_crossed"),
+    ("(?)", "asl"),
+    ("(%)", "handcuffs"),
+    ("(ci)", "cigarette"),
+];
+
+/// Splits a message's text into text and emoticon tokens. The longest shortcut at each position wins, and custom
+/// emoticons declared by the sender take precedence over standard ones with the same shortcut.
+#[cfg_attr(feature = "uniffi", uniffi::export)]
+pub fn tokenize_emoticons(
+    message: &PlainText,
+    custom_emoticons: Vec<CustomEmoticon>,
+) -> Vec<TextToken> {
+    let text = message.text.as_str();
+    let mut tokens = Vec::new();
+    let mut start = 0;
+    let mut index = 0;
+
+    while index < text.len() {
+        let rest = &text[index..];
+        let custom = custom_emoticons
+            .iter()
+            .filter(|emoticon| {
+                !emoticon.shortcut.is_empty() && rest.starts_with(&emoticon.shortcut)
+            })
+            .max_by_key(|emoticon| emoticon.shortcut.len());
+
+        let standard = STANDARD_EMOTICONS
+            .iter()
+            .filter(|(shortcut, _)| {
+                rest.get(..shortcut.len())
+                    .is_some_and(|start| start.eq_ignore_ascii_case(shortcut))
+            })
+            .max_by_key(|(shortcut, _)| shortcut.len());
+
+        let token = match (custom, standard) {
+            (Some(custom), Some((shortcut, _))) if custom.shortcut.len() >= shortcut.len() => {
+                Some(custom_token(custom))
+            }
+
+            (Some(custom), None) => Some(custom_token(custom)),
+            (_, Some((shortcut, name))) => Some(TextToken::Emoticon {
+                shortcut: text[index..index + shortcut.len()].to_string(),
+                name: name.to_string(),
+            }),
+
+            (None, None) => None,
+        };
+
+        match token {
+            Some(token) => {
+                if start < index {
+                    tokens.push(TextToken::Text(text[start..index].to_string()));
+                }
+
+                index += match &token {
+                    TextToken::Emoticon { shortcut, .. }
+                    | TextToken::CustomEmoticon { shortcut, .. } => shortcut.len(),
+                    TextToken::Text(text) => text.len(),
+                };
+
+                start = index;
+                tokens.push(token);
+            }
+
+            None => index += rest.chars().next().map(char::len_utf8).unwrap_or(1),
+        }
+    }
+
+    if start < text.len() {
+        tokens.push(TextToken::Text(text[start..].to_string()));
+    }
+
+    tokens
+}
+
+fn custom_token(emoticon: &CustomEmoticon) -> TextToken {
+    TextToken::CustomEmoticon {
+        shortcut: emoticon.shortcut.clone(),
+        msn_object: emoticon.msn_object.clone(),
+    }
+}
@@ -0,0 +1,9 @@
+use crate::enums::invite_status::InviteStatus;
+
+/// Result of inviting one contact to a [GroupChat][crate::GroupChat].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct InviteResult {
+    pub email: String,
+    pub status: InviteStatus,
+}
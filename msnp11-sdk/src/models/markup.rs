@@ -0,0 +1,397 @@
+//! Conversion of [PlainText] to and from HTML and Markdown.
+//!
+//! MSN applies a single style to the whole message, so parsing applies every style found anywhere in the markup to
+//! the entire text. Anything outside the supported subset is dropped.
+
+use crate::models::plain_text::PlainText;
+
+/// Renders a message as HTML, with the text escaped and the style applied through `b`, `i`, `u` and `s` tags and a
+/// `span` for the color and font.
+#[cfg_attr(feature = "uniffi", uniffi::export)]
+pub fn plain_text_to_html(message: &PlainText) -> String {
+    let mut html = escape_html(&message.text).replace('\n', "<br>");
+
+    for (enabled, tag) in [
+        (message.strikethrough, "s"),
+        (message.underline, "u"),
+        (message.italic, "i"),
+        (message.bold, "b"),
+    ] {
+        if enabled {
+            html = format!("<{tag}>{html}</{tag}>");
+        }
+    }
+
+    let font_name: String = message
+        .font_name
+        .chars()
+        .filter(|c| c.is_alphanumeric() || *c == ' ' || *c == '-')
+        .collect();
+
+    let direction = if message.right_to_left {
+        " dir=\"rtl\""
+    } else {
+        ""
+    };
+
+    format!(
+        "<span{direction} style=\"font-family: '{font_name}'; color: #{}\">{html}</span>",
+        normalize_color(&message.color)
+    )
+}
+
+/// Parses a restricted HTML subset into a message: `b`/`strong`, `i`/`em`, `u`/`ins`, `s`/`strike`/`del`, `br`, and
+/// colors from `font color` or a `color` style. Other tags are removed and their text kept.
+#[cfg_attr(feature = "uniffi", uniffi::export)]
+pub fn plain_text_from_html(html: &str) -> PlainText {
+    let mut message = PlainText::default();
+    let mut color = None;
+    let mut text = String::new();
+    let mut rest = html;
+
+    while let Some(start) = rest.find('<') {
+        text.push_str(&rest[..start]);
+        let Some(end) = rest[start..].find('>') else {
+            rest = &rest[start..];
+            break;
+        };
+
+        let tag = rest[start + 1..start + end].trim();
+        rest = &rest[start + end + 1..];
+
+        let closing = tag.starts_with('/');
+        let tag = tag.trim_start_matches('/');
+        let name = tag
+            .split(|c: char| c.is_whitespace() || c == '/')
+            .next()
+            .unwrap_or_default()
+            .to_ascii_lowercase();
+
+        match name.as_str() {
+            "b" | "strong" => message.bold = true,
+            "i" | "em" => message.italic = true,
+            "u" | "ins" => message.underline = true,
+            "s" | "strike" | "del" => message.strikethrough = true,
+            "br" => text.push('\n'),
+            "p" | "div" if closing => text.push('\n'),
+            "font" | "span" if !closing && color.is_none() => color = tag_color(tag),
+            _ => (),
+        }
+    }
+
+    text.push_str(rest);
+    message.text = unescape_html(&text).trim_end_matches('\n').to_string();
+
+    if let Some(color) = color {
+        message.color = color;
+    }
+
+    message
+}
+
+/// Renders a message as Markdown, with the text escaped and the style applied to every line. Underline is written as
+/// a `<u>` tag, since Markdown has no syntax for it, and the color is dropped.
+#[cfg_attr(feature = "uniffi", uniffi::export)]
+pub fn plain_text_to_markdown(message: &PlainText) -> String {
+    message
+        .text
+        .split('\n')
+        .map(|line| {
+            if line.trim().is_empty() {
+                return line.to_string();
+            }
+
+            let mut line = escape_markdown(line);
+            for (enabled, open, close) in [
+                (message.underline, "<u>", "</u>"),
+                (message.strikethrough, "~~", "~~"),
+                (message.italic, "*", "*"),
+                (message.bold, "**", "**"),
+            ] {
+                if enabled {
+                    line = format!("{open}{line}{close}");
+                }
+            }
+
+            line
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+/// Parses a restricted Markdown subset into a message: `**`/`__` bold, `*`/`_` italic, `~~` strikethrough, `<u>`
+/// underline and backslash escapes. Like CommonMark, markers only open or close emphasis next to text, so `2 * 3 * 4`
+/// stays as written, and markers without a matching pair are kept as text.
+#[cfg_attr(feature = "uniffi", uniffi::export)]
+pub fn plain_text_from_markdown(markdown: &str) -> PlainText {
+    let mut pieces = Vec::new();
+    let mut text = String::new();
+    let mut chars = markdown.char_indices();
+
+    while let Some((index, c)) = chars.next() {
+        let rest = &markdown[index..];
+        let marker = if c == '\\' {
+            if let Some((_, escaped)) = chars.next() {
+                text.push(escaped);
+            }
+
+            continue;
+        } else if rest.starts_with("**") {
+            Some((Style::Bold, "**"))
+        } else if rest.starts_with("__") {
+            Some((Style::Bold, "__"))
+        } else if rest.starts_with("~~") {
+            Some((Style::Strikethrough, "~~"))
+        } else if rest.starts_with("<u>") || rest.starts_with("<U>") {
+            Some((Style::Underline, "<u>"))
+        } else if rest.starts_with("</u>") || rest.starts_with("</U>") {
+            Some((Style::Underline, "</u>"))
+        } else if c == '*' {
+            Some((Style::Italic, "*"))
+        } else if c == '_' {
+            Some((Style::Italic, "_"))
+        } else {
+            None
+        };
+
+        match marker {
+            Some((style, marker)) => {
+                let previous = markdown[..index].chars().next_back();
+                let next = rest[marker.len()..].chars().next();
+                let (can_open, can_close) = match marker {
+                    "<u>" => (true, false),
+                    "</u>" => (false, true),
+                    _ => flanking(marker, previous, next),
+                };
+
+                pieces.push(Piece::Text(std::mem::take(&mut text)));
+                pieces.push(Piece::Marker {
+                    style,
+                    marker,
+                    can_open,
+                    can_close,
+                });
+
+                for _ in 1..marker.chars().count() {
+                    chars.next();
+                }
+            }
+
+            None => text.push(c),
+        }
+    }
+
+    pieces.push(Piece::Text(text));
+
+    // Closers pair with the nearest opener of the same marker, like `_` with `_` and `<u>` with `</u>`
+    let mut paired = vec![false; pieces.len()];
+    let mut openers: Vec<(&str, usize)> = Vec::new();
+
+    for (index, piece) in pieces.iter().enumerate() {
+        let Piece::Marker {
+            marker,
+            can_open,
+            can_close,
+            ..
+        } = piece
+        else {
+            continue;
+        };
+
+        let kind = marker.trim_start_matches("</").trim_start_matches('<');
+        let opener = openers.iter().rposition(|(other, _)| *other == kind);
+
+        match opener {
+            Some(position) if *can_close => {
+                let (_, opener) = openers.remove(position);
+                paired[opener] = true;
+                paired[index] = true;
+            }
+
+            _ if *can_open => openers.push((kind, index)),
+            _ => (),
+        }
+    }
+
+    let mut message = PlainText::default();
+    let mut text = String::new();
+
+    for (piece, paired) in pieces.iter().zip(paired) {
+        match piece {
+            Piece::Text(piece) => text.push_str(piece),
+            Piece::Marker { style, .. } if paired => match style {
+                Style::Bold => message.bold = true,
+                Style::Italic => message.italic = true,
+                Style::Underline => message.underline = true,
+                Style::Strikethrough => message.strikethrough = true,
+            },
+
+            Piece::Marker { marker, .. } => text.push_str(marker),
+        }
+    }
+
+    message.text = text;
+    message
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Style {
+    Bold,
+    Italic,
+    Underline,
+    Strikethrough,
+}
+
+#[derive(Debug)]
+enum Piece {
+    Text(String),
+    Marker {
+        style: Style,
+        marker: &'static str,
+        can_open: bool,
+        can_close: bool,
+    },
+}
+
+/// Returns whether a marker can open and close emphasis, following CommonMark's left- and right-flanking rules.
+fn flanking(marker: &str, previous: Option<char>, next: Option<char>) -> (bool, bool) {
+    let is_space = |c: Option<char>| c.is_none_or(char::is_whitespace);
+    let is_punctuation = |c: Option<char>| c.is_some_and(|c| c.is_ascii_punctuation());
+
+    let left = !is_space(next)
+        && (!is_punctuation(next) || is_space(previous) || is_punctuation(previous));
+    let right = !is_space(previous)
+        && (!is_punctuation(previous) || is_space(next) || is_punctuation(next));
+
+    if marker.starts_with('_') {
+        // Underscores inside words like snake_case aren't emphasis
+        (
+            left && (!right || is_punctuation(previous)),
+            right && (!left || is_punctuation(next)),
+        )
+    } else {
+        (left, right)
+    }
+}
+
+fn normalize_color(color: &str) -> String {
+    let mut color: String = color
+        .trim()
+        .trim_start_matches('#')
+        .chars()
+        .filter(char::is_ascii_hexdigit)
+        .take(6)
+        .collect();
+
+    while color.len() < 6 {
+        color.insert(0, '0');
+    }
+
+    color.to_ascii_lowercase()
+}
+
+/// Reads a color from a `color` attribute or style, as RGB hex without the leading `#` or zeros.
+fn tag_color(tag: &str) -> Option<String> {
+    let lowercase = tag.to_ascii_lowercase();
+
+    // Only a whole `color` name counts, not one ending like `background-color`
+    let start = lowercase.match_indices("color").find_map(|(index, name)| {
+        let before = lowercase[..index].chars().next_back();
+        let after = lowercase[index + name.len()..].trim_start();
+        let is_name = |c: char| c.is_alphanumeric() || c == '-' || c == '_';
+
+        (!before.is_some_and(is_name) && (after.starts_with('=') || after.starts_with(':')))
+            .then_some(index + name.len())
+    })?;
+
+    let value = lowercase[start..]
+        .trim_start_matches(|c: char| c.is_whitespace() || c == '=' || c == ':' || c == '"')
+        .trim_start_matches('\'');
+
+    let hex = value.strip_prefix('#')?;
+    let hex: String = hex.chars().take_while(char::is_ascii_hexdigit).collect();
+
+    let hex: String = match hex.len() {
+        3 => hex.chars().flat_map(|c| [c, c]).collect(),
+        6 => hex,
+        _ => return None,
+    };
+
+    // Same format as received messages
+    u32::from_str_radix(&hex, 16)
+        .ok()
+        .map(|color| format!("{color:x}"))
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&#39;")
+}
+
+fn unescape_html(text: &str) -> String {
+    let mut unescaped = String::new();
+    let mut rest = text;
+
+    while let Some(start) = rest.find('&') {
+        unescaped.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        let entity = rest
+            .find(';')
+            .filter(|end| *end <= 10)
+            .map(|end| (&rest[1..end], end));
+
+        let decoded = entity.and_then(|(entity, end)| {
+            let c = match entity {
+                "amp" => Some('&'),
+                "lt" => Some('<'),
+                "gt" => Some('>'),
+                "quot" => Some('"'),
+                "apos" => Some('\''),
+                "nbsp" => Some(' '),
+                _ => entity
+                    .strip_prefix("#x")
+                    .or_else(|| entity.strip_prefix("#X"))
+                    .and_then(|hex| u32::from_str_radix(hex, 16).ok())
+                    .or_else(|| entity.strip_prefix('#').and_then(|dec| dec.parse().ok()))
+                    .and_then(char::from_u32),
+            };
+
+            c.map(|c| (c, end))
+        });
+
+        match decoded {
+            Some((c, end)) => {
+                unescaped.push(c);
+                rest = &rest[end + 1..];
+            }
+
+            None => {
+                unescaped.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+
+    unescaped.push_str(rest);
+    unescaped
+}
+
+fn escape_markdown(text: &str) -> String {
+    let mut escaped = String::new();
+    for c in text.chars() {
+        if matches!(
+            c,
+            '\\' | '`' | '*' | '_' | '~' | '[' | ']' | '(' | ')' | '#' | '<' | '>' | '|' | '!'
+        ) {
+            escaped.push('\\');
+        }
+
+        escaped.push(c);
+    }
+
+    escaped
+}
@@ -0,0 +1,8 @@
+/// Identifies a message sent without waiting for its acknowledgement, to match it with
+/// [MessageDelivered][crate::Event::MessageDelivered] and [MessageFailed][crate::Event::MessageFailed] events.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct MessageHandle {
+    /// Transaction ID of the message's first MSG.
+    pub id: u32,
+}
@@ -0,0 +1,7 @@
+/// A MIME header of a message, like `P4-Context: Bob's alias`.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct MimeHeader {
+    pub name: String,
+    pub value: String,
+}
@@ -1,7 +1,24 @@
+pub mod auto_away_settings;
+pub mod challenge_keys;
+pub mod client_capabilities;
+pub mod contact_request;
+pub mod current_media;
+pub mod custom_emoticon;
+pub mod emoticons;
 #[cfg(feature = "file-transfers")]
 pub mod file_transfer_request;
+pub mod invite_result;
+pub mod markup;
+pub mod message_handle;
+pub mod mime_header;
 pub mod msn_object;
+pub mod participant;
+pub mod passport_ticket;
 pub mod personal_message;
 pub mod plain_text;
 pub mod presence;
+pub mod profile;
+pub mod protocol_log_entry;
+pub mod proxy;
+pub mod service_url;
 pub mod user_data;
@@ -0,0 +1,12 @@
+use crate::models::client_capabilities::ClientCapabilities;
+
+/// A contact in a Switchboard session.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct Participant {
+    pub email: String,
+    pub display_name: String,
+
+    /// Only sent by servers that include them in `JOI` and `IRO`.
+    pub capabilities: Option<ClientCapabilities>,
+}
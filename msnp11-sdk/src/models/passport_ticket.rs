@@ -0,0 +1,27 @@
+use std::time::SystemTime;
+
+/// A ticket returned by an [Authenticator][crate::Authenticator]. It can be cached and used to log in again without
+/// going through authentication, as long as it hasn't expired.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct PassportTicket {
+    pub ticket: String,
+    pub obtained_at: SystemTime,
+    /// Not every authentication method reports when the ticket expires.
+    pub expires_at: Option<SystemTime>,
+}
+
+impl PassportTicket {
+    pub fn new(ticket: String, expires_at: Option<SystemTime>) -> Self {
+        Self {
+            ticket,
+            obtained_at: SystemTime::now(),
+            expires_at,
+        }
+    }
+
+    pub fn is_expired(&self) -> bool {
+        self.expires_at
+            .is_some_and(|expires_at| expires_at <= SystemTime::now())
+    }
+}
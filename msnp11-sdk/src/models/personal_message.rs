@@ -1,3 +1,4 @@
+use crate::models::current_media::{CurrentMedia, current_media_format};
 use serde::{Deserialize, Serialize};
 
 /// A user's personal message. The text itself goes in [psm][PersonalMessage::psm], while [current_media][PersonalMessage::current_media]
@@ -8,6 +9,6 @@ use serde::{Deserialize, Serialize};
 pub struct PersonalMessage {
     #[serde(rename = "PSM")]
     pub psm: String,
-    #[serde(rename = "CurrentMedia")]
-    pub current_media: String,
+    #[serde(rename = "CurrentMedia", with = "current_media_format", default)]
+    pub current_media: Option<CurrentMedia>,
 }
@@ -1,3 +1,5 @@
+use crate::mime::MimeMessage;
+
 /// Represents a plain text message. Colors are defined in RGB hex(converted to BGR internally).
 #[derive(Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
@@ -7,66 +9,84 @@ pub struct PlainText {
     pub underline: bool,
     pub strikethrough: bool,
     pub color: String,
+    /// Font family name, like "Segoe UI".
+    pub font_name: String,
+    /// Windows character set of the font, like 0 for ANSI or 0x80 for Shift JIS.
+    pub charset: u8,
+    /// Windows pitch and family of the font, like 0x22 for a variable pitch sans-serif font.
+    pub pitch_family: u8,
+    /// Whether the text is written right to left.
+    pub right_to_left: bool,
     pub text: String,
 }
 
-impl PlainText {
-    pub(crate) fn new(payload: String) -> Self {
-        let im_format = payload
-            .lines()
-            .nth(2)
-            .unwrap_or_default()
-            .replace("X-MMS-IM-Format: ", "");
-
-        let mut im_format = im_format.split(";");
-        let formatting = im_format.nth(1).unwrap_or_default();
-        let color = im_format.next().unwrap_or_default();
-
-        let bold = formatting.contains("B");
-        let italic = formatting.contains("I");
-        let underline = formatting.contains("U");
-        let strikethrough = formatting.contains("S");
-        let mut color = color.replace("CO=", "").trim().to_string();
-
-        while color.len() < 6 {
-            color.insert(0, '0');
+impl Default for PlainText {
+    fn default() -> Self {
+        Self {
+            bold: false,
+            italic: false,
+            underline: false,
+            strikethrough: false,
+            color: "0".to_string(),
+            font_name: "Microsoft Sans Serif".to_string(),
+            charset: 1,
+            pitch_family: 0,
+            right_to_left: false,
+            text: String::new(),
         }
+    }
+}
 
-        // MSN uses BGR... just why
-        let color: u32 = if color.len() <= 6 {
-            let color = color.drain(..6);
-            let color = color.as_str();
-            let color = u32::from_str_radix(color, 16).unwrap_or(0);
-
-            let r = (color & 0xFF0000) >> 16;
-            let b = (color & 0x0000FF) << 16;
-            let g = color & 0x00FF00;
-            r | g | b
-        } else {
-            0
+impl PlainText {
+    pub(crate) fn new(payload: &str) -> Self {
+        let message = MimeMessage::parse(payload);
+        let mut plain_text = Self {
+            text: message.body.replace("\r\n", "\n"),
+            ..Self::default()
         };
 
-        let color = format!("{color:x}");
-        let text = payload
-            .split("\r\n\r\n")
-            .nth(1)
-            .unwrap_or_default()
-            .replace("\r\n", "\n");
-
-        Self {
-            bold,
-            italic,
-            underline,
-            strikethrough,
-            color,
-            text,
+        let im_format = message.header("X-MMS-IM-Format").unwrap_or_default();
+        for attribute in im_format.split(';') {
+            let Some((key, value)) = attribute.split_once('=') else {
+                continue;
+            };
+
+            let value = value.trim();
+            match key.trim() {
+                "FN" => {
+                    plain_text.font_name = urlencoding::decode(value)
+                        .map(|font_name| font_name.into_owned())
+                        .unwrap_or_else(|_| value.to_string());
+                }
+
+                "EF" => {
+                    plain_text.bold = value.contains('B');
+                    plain_text.italic = value.contains('I');
+                    plain_text.underline = value.contains('U');
+                    plain_text.strikethrough = value.contains('S');
+                }
+
+                "CO" => plain_text.color = swap_red_blue(value),
+                "CS" => plain_text.charset = u8::from_str_radix(value, 16).unwrap_or(0),
+                "PF" => plain_text.pitch_family = u8::from_str_radix(value, 16).unwrap_or(0),
+                "RL" => plain_text.right_to_left = value == "1",
+                _ => (),
+            }
         }
+
+        plain_text
     }
 
     pub(crate) fn payload(&self) -> String {
         let mut message = String::from("MIME-Version: 1.0\r\n");
         message.push_str("Content-Type: text/plain; charset=UTF-8\r\n");
-        message.push_str("X-MMS-IM-Format: FN=Microsoft%20Sans%20Serif; EF=");
+        message.push_str(
+            format!(
+                "X-MMS-IM-Format: FN={}; EF=",
+                urlencoding::encode(&self.font_name)
+            )
+            .as_str(),
+        );
 
         if self.bold {
             message.push('B');
@@ -84,27 +104,20 @@ impl PlainText {
             message.push('S');
         }
 
-        let mut color = self.color.trim().replace("#", "");
-        while color.len() < 6 {
-            color.insert(0, '0');
-        }
+        let color = swap_red_blue(&self.color);
+        message.push_str(
+            format!(
+                "; CO={color}; CS={:x}; PF={:x}",
+                self.charset, self.pitch_family
+            )
+            .as_str(),
+        );
 
-        // MSN uses BGR... just why
-        let color: u32 = if self.color.len() <= 6 {
-            let color = color.drain(..6);
-            let color = color.as_str();
-            let color = u32::from_str_radix(color, 16).unwrap_or(0);
-
-            let r = (color & 0xFF0000) >> 16;
-            let b = (color & 0x0000FF) << 16;
-            let g = color & 0x00FF00;
-            b | g | r
-        } else {
-            0
-        };
+        if self.right_to_left {
+            message.push_str("; RL=1");
+        }
 
-        let color = format!("{color:x}");
-        message.push_str(format!("; CO={color}; CS=1; PF=0\r\n\r\n").as_str());
+        message.push_str("\r\n\r\n");
         message.push_str(
             self.text
                 .replace("\n", "\r\n")
@@ -115,3 +128,21 @@ impl PlainText {
         message
     }
 }
+
+// MSN uses BGR... just why
+fn swap_red_blue(color: &str) -> String {
+    let mut color = color.trim().replace("#", "");
+    if color.len() > 6 {
+        return "0".to_string();
+    }
+
+    while color.len() < 6 {
+        color.insert(0, '0');
+    }
+
+    let color = u32::from_str_radix(&color, 16).unwrap_or(0);
+    let r = (color & 0xFF0000) >> 16;
+    let b = (color & 0x0000FF) << 16;
+    let g = color & 0x00FF00;
+    format!("{:x}", r | g | b)
+}
@@ -1,4 +1,5 @@
 use crate::enums::msnp_status::MsnpStatus;
+use crate::models::client_capabilities::ClientCapabilities;
 use crate::models::msn_object::MsnObject;
 
 /// Represents a contact's presence information.
@@ -6,16 +7,16 @@ use crate::models::msn_object::MsnObject;
 #[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
 pub struct Presence {
     pub status: MsnpStatus,
-    pub client_id: u64,
+    pub client_id: ClientCapabilities,
     pub msn_object: Option<MsnObject>,
     pub msn_object_string: Option<String>,
 }
 
 impl Presence {
-    pub(crate) fn new_without_object(status: MsnpStatus) -> Self {
+    pub(crate) fn new_without_object(status: MsnpStatus, client_id: ClientCapabilities) -> Self {
         Self {
             status,
-            client_id: 0x40000000,
+            client_id,
             msn_object: None,
             msn_object_string: None,
         }
@@ -0,0 +1,14 @@
+/// The user's profile, sent by the server after logging in.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct Profile {
+    pub country: String,
+    /// The preferred language's LCID, like 1033 for English (US).
+    pub language: u32,
+    pub kid: bool,
+    pub email_enabled: bool,
+    pub mspauth: String,
+    /// The user's IP address as seen by the server.
+    pub client_ip: String,
+    pub client_port: u16,
+}
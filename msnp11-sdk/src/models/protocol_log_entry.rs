@@ -0,0 +1,13 @@
+use crate::enums::protocol_direction::ProtocolDirection;
+use crate::enums::server_type::ServerType;
+
+/// A command sent or received, passed to the protocol log handler. Passwords, tickets and CKI strings are redacted from
+/// the command and payloads are only counted.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct ProtocolLogEntry {
+    pub direction: ProtocolDirection,
+    pub server_type: ServerType,
+    pub command: String,
+    pub payload_length: u32,
+}
@@ -0,0 +1,13 @@
+use crate::enums::proxy_type::ProxyType;
+
+/// A proxy server used for every connection the client makes, including Switchboards,
+/// direct P2P connections and Passport requests. Credentials are optional.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct Proxy {
+    pub proxy_type: ProxyType,
+    pub server: String,
+    pub port: u16,
+    pub username: Option<String>,
+    pub password: Option<String>,
+}
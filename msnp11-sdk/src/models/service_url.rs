@@ -0,0 +1,12 @@
+/// URLs returned by the server for a service page. Apps build the signed Passport redirect by sending [rru][ServiceUrl::rru]
+/// and [id][ServiceUrl::id] to [passport_url][ServiceUrl::passport_url] along with the MSPAuth from the
+/// [Profile][crate::Profile].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct ServiceUrl {
+    /// The page to be redirected to, relative to the service.
+    pub rru: String,
+    pub passport_url: String,
+    /// The ID of the site the page belongs to.
+    pub id: u32,
+}
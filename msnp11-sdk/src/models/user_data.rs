@@ -1,8 +1,17 @@
+use crate::enums::msnp_status::MsnpStatus;
+use crate::models::client_capabilities::ClientCapabilities;
+use crate::models::passport_ticket::PassportTicket;
+use crate::models::personal_message::PersonalMessage;
+
 #[derive(Debug, Clone)]
 pub(crate) struct UserData {
     pub(crate) email: Option<String>,
     pub(crate) display_picture: Option<Vec<u8>>,
     pub(crate) msn_object: Option<String>,
+    pub(crate) ticket: Option<PassportTicket>,
+    pub(crate) capabilities: ClientCapabilities,
+    pub(crate) status: Option<MsnpStatus>,
+    pub(crate) personal_message: Option<PersonalMessage>,
 }
 
 impl UserData {
@@ -11,6 +20,10 @@ impl UserData {
             email: None,
             display_picture: None,
             msn_object: None,
+            ticket: None,
+            capabilities: ClientCapabilities::default(),
+            status: None,
+            personal_message: None,
         }
     }
 }
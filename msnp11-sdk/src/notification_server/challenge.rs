@@ -0,0 +1,51 @@
+use md5::{Digest, Md5};
+
+const MODULUS: u64 = 0x7FFFFFFF;
+const MULTIPLIER: u64 = 0x0E79A9C1;
+
+/// Computes the MSNP11 challenge response sent with `QRY`.
+pub(crate) fn challenge_response(challenge: &str, product_id: &str, product_key: &str) -> String {
+    let md5_hash = Md5::digest(format!("{challenge}{product_key}"));
+    let md5_ints: Vec<u64> = md5_hash
+        .chunks_exact(4)
+        .map(|chunk| {
+            u64::from(u32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]])) & MODULUS
+        })
+        .collect();
+
+    // Always padded, even if the length is already a multiple of 8
+    let mut challenge_string = format!("{challenge}{product_id}").into_bytes();
+    let padding = 8 - challenge_string.len() % 8;
+    challenge_string.extend(std::iter::repeat_n(b'0', padding));
+
+    let challenge_ints: Vec<u64> = challenge_string
+        .chunks_exact(4)
+        .map(|chunk| u64::from(u32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]])))
+        .collect();
+
+    let mut high = 0;
+    let mut low = 0;
+    for pair in challenge_ints.chunks_exact(2) {
+        let mut temp = (pair[0] * MULTIPLIER) % MODULUS;
+        temp = (md5_ints[0] * (temp + high) + md5_ints[1]) % MODULUS;
+
+        high = (pair[1] + temp) % MODULUS;
+        high = (md5_ints[2] * high + md5_ints[3]) % MODULUS;
+
+        low += high + temp;
+    }
+
+    high = (high + md5_ints[1]) % MODULUS;
+    low = (low + md5_ints[3]) % MODULUS;
+
+    // Both halves of the hash are XORed with the high and low keys
+    let mut key = [0; 8];
+    key[..4].copy_from_slice(&(high as u32).to_le_bytes());
+    key[4..].copy_from_slice(&(low as u32).to_le_bytes());
+
+    md5_hash
+        .iter()
+        .enumerate()
+        .map(|(i, byte)| format!("{:02x}", byte ^ key[i % 8]))
+        .collect()
+}
@@ -2,6 +2,7 @@ use crate::enums::event::Event;
 use crate::enums::internal_event::InternalEvent;
 use crate::enums::msnp_list::MsnpList;
 use crate::errors::contact_error::ContactError;
+use crate::protocol_log::redact;
 use log::trace;
 use std::sync::atomic::{AtomicU32, Ordering};
 use tokio::sync::{broadcast, mpsc};
@@ -25,7 +26,7 @@ pub async fn send(
             .await
             .or(Err(ContactError::TransmittingError))?;
 
-        trace!("C: {command}");
+        trace!("C: {}", redact(&command));
     } else {
         let list = match list {
             MsnpList::ForwardList => "FL",
@@ -41,7 +42,7 @@ pub async fn send(
             .await
             .or(Err(ContactError::TransmittingError))?;
 
-        trace!("C: {command}");
+        trace!("C: {}", redact(&command));
     }
 
     loop {
@@ -50,7 +51,7 @@ pub async fn send(
             .await
             .or(Err(ContactError::ReceivingError))?
         {
-            trace!("S: {reply}");
+            trace!("S: {}", redact(&reply));
 
             let args: Vec<&str> = reply.split_ascii_whitespace().collect();
             match *args.first().unwrap_or(&"") {
@@ -127,14 +128,14 @@ pub async fn send_with_group(
         .await
         .or(Err(ContactError::TransmittingError))?;
 
-    trace!("C: {command}");
+    trace!("C: {}", redact(&command));
     loop {
         if let InternalEvent::ServerReply(reply) = internal_rx
             .recv()
             .await
             .or(Err(ContactError::ReceivingError))?
         {
-            trace!("S: {reply}");
+            trace!("S: {}", redact(&reply));
 
             let args: Vec<&str> = reply.split_ascii_whitespace().collect();
             match *args.first().unwrap_or(&"") {
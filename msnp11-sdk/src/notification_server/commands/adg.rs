@@ -1,5 +1,6 @@
 use crate::enums::internal_event::InternalEvent;
 use crate::errors::contact_error::ContactError;
+use crate::protocol_log::redact;
 use log::trace;
 use std::sync::atomic::{AtomicU32, Ordering};
 use tokio::sync::{broadcast, mpsc};
@@ -20,7 +21,7 @@ pub async fn send(
         .await
         .or(Err(ContactError::TransmittingError))?;
 
-    trace!("C: {command}");
+    trace!("C: {}", redact(&command));
 
     loop {
         if let InternalEvent::ServerReply(reply) = internal_rx
@@ -28,7 +29,7 @@ pub async fn send(
             .await
             .or(Err(ContactError::ReceivingError))?
         {
-            trace!("S: {reply}");
+            trace!("S: {}", redact(&reply));
 
             let args: Vec<&str> = reply.split_ascii_whitespace().collect();
             match *args.first().unwrap_or(&"") {
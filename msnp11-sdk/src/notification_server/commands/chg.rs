@@ -2,6 +2,7 @@ use crate::enums::internal_event::InternalEvent;
 use crate::enums::msnp_status::MsnpStatus;
 use crate::errors::sdk_error::SdkError;
 use crate::models::presence::Presence;
+use crate::protocol_log::redact;
 use log::trace;
 use std::sync::atomic::{AtomicU32, Ordering};
 use tokio::sync::{broadcast, mpsc};
@@ -27,7 +28,7 @@ pub async fn send(
         MsnpStatus::AppearOffline => "HDN",
     };
 
-    let mut command = format!("CHG {tr_id} {status} {}\r\n", presence.client_id);
+    let mut command = format!("CHG {tr_id} {status} {}\r\n", presence.client_id.bits());
     if let Some(msn_object) = msn_object {
         command = command.replace(
             "\r\n",
@@ -40,13 +41,13 @@ pub async fn send(
         .await
         .or(Err(SdkError::TransmittingError))?;
 
-    trace!("C: {command}");
+    trace!("C: {}", redact(&command));
 
     loop {
         if let InternalEvent::ServerReply(reply) =
             internal_rx.recv().await.or(Err(SdkError::ReceivingError))?
         {
-            trace!("S: {reply}");
+            trace!("S: {}", redact(&reply));
 
             let args: Vec<&str> = reply.split_ascii_whitespace().collect();
             match *args.first().unwrap_or(&"") {
@@ -1,5 +1,6 @@
 use crate::enums::internal_event::InternalEvent;
 use crate::errors::sdk_error::SdkError;
+use crate::protocol_log::redact;
 use log::trace;
 use std::sync::atomic::{AtomicU32, Ordering};
 use tokio::sync::{broadcast, mpsc};
@@ -22,13 +23,13 @@ pub async fn send(
         .send(command.as_bytes().to_vec())
         .await
         .or(Err(SdkError::TransmittingError))?;
-    trace!("C: {command}");
+    trace!("C: {}", redact(&command));
 
     loop {
         if let InternalEvent::ServerReply(reply) =
             internal_rx.recv().await.or(Err(SdkError::ReceivingError))?
         {
-            trace!("S: {reply}");
+            trace!("S: {}", redact(&reply));
 
             let args: Vec<&str> = reply.split_ascii_whitespace().collect();
             match *args.first().unwrap_or(&"") {
@@ -1,5 +1,7 @@
+use crate::enums::add_notification_mode::AddNotificationMode;
 use crate::enums::internal_event::InternalEvent;
 use crate::errors::sdk_error::SdkError;
+use crate::protocol_log::redact;
 use log::trace;
 use std::sync::atomic::{AtomicU32, Ordering};
 use tokio::sync::{broadcast, mpsc};
@@ -8,24 +10,25 @@ pub async fn send(
     tr_id: &AtomicU32,
     ns_tx: &mpsc::Sender<Vec<u8>>,
     internal_rx: &mut broadcast::Receiver<InternalEvent>,
-    gtc: &str,
+    gtc: AddNotificationMode,
 ) -> Result<(), SdkError> {
     tr_id.fetch_add(1, Ordering::SeqCst);
     let tr_id = tr_id.load(Ordering::SeqCst);
 
+    let gtc = gtc.as_str();
     let command = format!("GTC {tr_id} {gtc}\r\n");
     ns_tx
         .send(command.as_bytes().to_vec())
         .await
         .or(Err(SdkError::TransmittingError))?;
 
-    trace!("C: {command}");
+    trace!("C: {}", redact(&command));
 
     loop {
         if let InternalEvent::ServerReply(reply) =
             internal_rx.recv().await.or(Err(SdkError::ReceivingError))?
         {
-            trace!("S: {reply}");
+            trace!("S: {}", redact(&reply));
 
             let args: Vec<&str> = reply.split_ascii_whitespace().collect();
             if *args.first().unwrap_or(&"") == "GTC"
@@ -5,12 +5,15 @@ pub mod chg;
 pub mod cvr;
 pub mod gcf;
 pub mod gtc;
+pub mod pag;
 pub mod prp;
+pub mod qry;
 pub mod reg;
 pub mod rem;
 pub mod rmg;
 pub mod sbp;
 pub mod syn;
+pub mod url;
 pub mod usr_i;
 pub mod usr_s;
 pub mod uux;
@@ -0,0 +1,58 @@
+use crate::enums::internal_event::InternalEvent;
+use crate::errors::contact_error::ContactError;
+use crate::protocol_log::redact;
+use log::trace;
+use quick_xml::escape::escape;
+use std::sync::atomic::{AtomicU32, Ordering};
+use tokio::sync::{broadcast, mpsc};
+
+pub async fn send(
+    tr_id: &AtomicU32,
+    ns_tx: &mpsc::Sender<Vec<u8>>,
+    internal_rx: &mut broadcast::Receiver<InternalEvent>,
+    email: &str,
+    message: &str,
+) -> Result<(), ContactError> {
+    tr_id.fetch_add(1, Ordering::SeqCst);
+    let tr_id = tr_id.load(Ordering::SeqCst);
+
+    let payload = format!(
+        "<TEXT xml:space=\"preserve\" enc=\"utf-8\">{}</TEXT>",
+        escape(message)
+    );
+
+    let command = format!("PAG {tr_id} {email} {}\r\n{payload}", payload.len());
+    ns_tx
+        .send(command.as_bytes().to_vec())
+        .await
+        .or(Err(ContactError::TransmittingError))?;
+
+    trace!("C: {}", redact(&command));
+
+    loop {
+        if let InternalEvent::ServerReply(reply) = internal_rx
+            .recv()
+            .await
+            .or(Err(ContactError::ReceivingError))?
+        {
+            trace!("S: {}", redact(&reply));
+
+            let args: Vec<&str> = reply.split_ascii_whitespace().collect();
+            match *args.first().unwrap_or(&"") {
+                "PAG" if *args.get(1).unwrap_or(&"") == tr_id.to_string() => {
+                    return Ok(());
+                }
+
+                "201" | "208" if *args.get(1).unwrap_or(&"") == tr_id.to_string() => {
+                    return Err(ContactError::InvalidContact);
+                }
+
+                "500" | "800" if *args.get(1).unwrap_or(&"") == tr_id.to_string() => {
+                    return Err(ContactError::ServerError);
+                }
+
+                _ => (),
+            }
+        }
+    }
+}
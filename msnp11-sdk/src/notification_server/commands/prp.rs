@@ -1,40 +1,55 @@
 use crate::enums::internal_event::InternalEvent;
 use crate::errors::sdk_error::SdkError;
+use crate::protocol_log::redact;
 use log::trace;
 use std::sync::atomic::{AtomicU32, Ordering};
 use tokio::sync::{broadcast, mpsc};
 
+/// Sets a user property. The value must already be encoded, and an empty one removes the property.
 pub async fn send(
     tr_id: &AtomicU32,
     ns_tx: &mpsc::Sender<Vec<u8>>,
     internal_rx: &mut broadcast::Receiver<InternalEvent>,
-    display_name: &str,
+    property: &str,
+    value: &str,
 ) -> Result<(), SdkError> {
     tr_id.fetch_add(1, Ordering::SeqCst);
     let tr_id = tr_id.load(Ordering::SeqCst);
 
-    let display_name = urlencoding::encode(display_name);
-    let command = format!("PRP {tr_id} MFN {display_name}\r\n");
+    let command = if value.is_empty() {
+        format!("PRP {tr_id} {property}\r\n")
+    } else {
+        format!("PRP {tr_id} {property} {value}\r\n")
+    };
+
     ns_tx
         .send(command.as_bytes().to_vec())
         .await
         .or(Err(SdkError::TransmittingError))?;
 
-    trace!("C: {command}");
+    trace!("C: {}", redact(&command));
 
     loop {
         if let InternalEvent::ServerReply(reply) =
             internal_rx.recv().await.or(Err(SdkError::ReceivingError))?
         {
-            trace!("S: {reply}");
+            trace!("S: {}", redact(&reply));
 
             let args: Vec<&str> = reply.split_ascii_whitespace().collect();
-            if *args.first().unwrap_or(&"") == "PRP"
-                && *args.get(1).unwrap_or(&"") == tr_id.to_string()
-                && *args.get(2).unwrap_or(&"") == "MFN"
-                && *args.get(3).unwrap_or(&"") == display_name
-            {
-                return Ok(());
+            match *args.first().unwrap_or(&"") {
+                "PRP"
+                    if *args.get(1).unwrap_or(&"") == tr_id.to_string()
+                        && *args.get(2).unwrap_or(&"") == property
+                        && *args.get(3).unwrap_or(&"") == value =>
+                {
+                    return Ok(());
+                }
+
+                "209" | "500" if *args.get(1).unwrap_or(&"") == tr_id.to_string() => {
+                    return Err(SdkError::ServerError);
+                }
+
+                _ => (),
             }
         }
     }
@@ -0,0 +1,69 @@
+use crate::enums::internal_event::InternalEvent;
+use crate::errors::sdk_error::SdkError;
+use crate::models::challenge_keys::ChallengeKeys;
+use crate::notification_server::challenge::challenge_response;
+use crate::protocol_log::redact;
+use log::trace;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::time::Duration;
+use tokio::sync::{broadcast, mpsc};
+
+/// How long to wait for the server to accept a challenge response.
+const REPLY_TIMEOUT: Duration = Duration::from_secs(30);
+
+pub async fn send(
+    tr_id: &AtomicU32,
+    ns_tx: &mpsc::Sender<Vec<u8>>,
+    internal_rx: &mut broadcast::Receiver<InternalEvent>,
+    challenge: &str,
+    challenge_keys: &ChallengeKeys,
+) -> Result<(), SdkError> {
+    tr_id.fetch_add(1, Ordering::SeqCst);
+    let tr_id = tr_id.load(Ordering::SeqCst);
+
+    let response = challenge_response(
+        challenge,
+        &challenge_keys.product_id,
+        &challenge_keys.product_key,
+    );
+
+    let command = format!(
+        "QRY {tr_id} {} {}\r\n{response}",
+        challenge_keys.product_id,
+        response.len()
+    );
+
+    ns_tx
+        .send(command.as_bytes().to_vec())
+        .await
+        .or(Err(SdkError::TransmittingError))?;
+
+    trace!("C: {}", redact(&command));
+
+    let reply = async {
+        loop {
+            if let InternalEvent::ServerReply(reply) =
+                internal_rx.recv().await.or(Err(SdkError::ReceivingError))?
+            {
+                trace!("S: {}", redact(&reply));
+
+                let args: Vec<&str> = reply.split_ascii_whitespace().collect();
+                match *args.first().unwrap_or(&"") {
+                    "QRY" if *args.get(1).unwrap_or(&"") == tr_id.to_string() => {
+                        return Ok(());
+                    }
+
+                    "540" if *args.get(1).unwrap_or(&"") == tr_id.to_string() => {
+                        return Err(SdkError::ServerError);
+                    }
+
+                    _ => (),
+                }
+            }
+        }
+    };
+
+    tokio::time::timeout(REPLY_TIMEOUT, reply)
+        .await
+        .or(Err(SdkError::ReceivingError))?
+}
@@ -1,6 +1,7 @@
 use crate::enums::internal_event::InternalEvent;
 use crate::enums::msnp_list::MsnpList;
 use crate::errors::contact_error::ContactError;
+use crate::protocol_log::redact;
 use log::trace;
 use std::sync::atomic::{AtomicU32, Ordering};
 use tokio::sync::{broadcast, mpsc};
@@ -29,7 +30,7 @@ pub async fn send(
         .await
         .or(Err(ContactError::TransmittingError))?;
 
-    trace!("C: {command}");
+    trace!("C: {}", redact(&command));
 
     loop {
         if let InternalEvent::ServerReply(reply) = internal_rx
@@ -37,7 +38,7 @@ pub async fn send(
             .await
             .or(Err(ContactError::ReceivingError))?
         {
-            trace!("S: {reply}");
+            trace!("S: {}", redact(&reply));
 
             let args: Vec<&str> = reply.split_ascii_whitespace().collect();
             match *args.first().unwrap_or(&"") {
@@ -82,7 +83,7 @@ pub async fn send_with_forward_list(
         .await
         .or(Err(ContactError::TransmittingError))?;
 
-    trace!("C: {command}");
+    trace!("C: {}", redact(&command));
 
     loop {
         if let InternalEvent::ServerReply(reply) = internal_rx
@@ -90,7 +91,7 @@ pub async fn send_with_forward_list(
             .await
             .or(Err(ContactError::ReceivingError))?
         {
-            trace!("S: {reply}");
+            trace!("S: {}", redact(&reply));
 
             let args: Vec<&str> = reply.split_ascii_whitespace().collect();
             match *args.first().unwrap_or(&"") {
@@ -136,7 +137,7 @@ pub async fn send_with_group(
         .await
         .or(Err(ContactError::TransmittingError))?;
 
-    trace!("C: {command}");
+    trace!("C: {}", redact(&command));
 
     loop {
         if let InternalEvent::ServerReply(reply) = internal_rx
@@ -144,7 +145,7 @@ pub async fn send_with_group(
             .await
             .or(Err(ContactError::ReceivingError))?
         {
-            trace!("S: {reply}");
+            trace!("S: {}", redact(&reply));
 
             let args: Vec<&str> = reply.split_ascii_whitespace().collect();
             match *args.first().unwrap_or(&"") {
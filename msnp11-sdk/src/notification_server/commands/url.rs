@@ -0,0 +1,57 @@
+use crate::enums::internal_event::InternalEvent;
+use crate::enums::service_url_kind::ServiceUrlKind;
+use crate::errors::sdk_error::SdkError;
+use crate::models::service_url::ServiceUrl;
+use crate::protocol_log::redact;
+use log::trace;
+use std::sync::atomic::{AtomicU32, Ordering};
+use tokio::sync::{broadcast, mpsc};
+
+pub async fn send(
+    tr_id: &AtomicU32,
+    ns_tx: &mpsc::Sender<Vec<u8>>,
+    internal_rx: &mut broadcast::Receiver<InternalEvent>,
+    kind: &ServiceUrlKind,
+) -> Result<ServiceUrl, SdkError> {
+    tr_id.fetch_add(1, Ordering::SeqCst);
+    let tr_id = tr_id.load(Ordering::SeqCst);
+
+    let command = format!("URL {tr_id} {}\r\n", kind.as_args());
+    ns_tx
+        .send(command.as_bytes().to_vec())
+        .await
+        .or(Err(SdkError::TransmittingError))?;
+
+    trace!("C: {}", redact(&command));
+
+    loop {
+        if let InternalEvent::ServerReply(reply) =
+            internal_rx.recv().await.or(Err(SdkError::ReceivingError))?
+        {
+            trace!("S: {}", redact(&reply));
+
+            let args: Vec<&str> = reply.split_ascii_whitespace().collect();
+            match *args.first().unwrap_or(&"") {
+                "URL" if *args.get(1).unwrap_or(&"") == tr_id.to_string() => {
+                    let (Some(rru), Some(passport_url), Some(id)) =
+                        (args.get(2), args.get(3), args.get(4))
+                    else {
+                        return Err(SdkError::ServerError);
+                    };
+
+                    return Ok(ServiceUrl {
+                        rru: rru.to_string(),
+                        passport_url: passport_url.to_string(),
+                        id: id.parse().or(Err(SdkError::ServerError))?,
+                    });
+                }
+
+                "201" | "500" | "710" if *args.get(1).unwrap_or(&"") == tr_id.to_string() => {
+                    return Err(SdkError::ServerError);
+                }
+
+                _ => (),
+            }
+        }
+    }
+}
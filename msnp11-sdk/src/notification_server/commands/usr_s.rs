@@ -1,5 +1,6 @@
 use crate::enums::internal_event::InternalEvent;
 use crate::errors::sdk_error::SdkError;
+use crate::protocol_log::redact;
 use log::trace;
 use std::sync::atomic::{AtomicU32, Ordering};
 use tokio::sync::{broadcast, mpsc};
@@ -9,6 +10,27 @@ pub async fn send(
     ns_tx: &mpsc::Sender<Vec<u8>>,
     internal_rx: &mut broadcast::Receiver<InternalEvent>,
     token: &str,
+) -> Result<(), SdkError> {
+    send_ticket(tr_id, ns_tx, internal_rx, token, false).await
+}
+
+/// Sends a ticket from a previous login, returning [TicketRejected][SdkError::TicketRejected] instead of
+/// [ServerIsBusy][SdkError::ServerIsBusy] if the server doesn't accept it.
+pub async fn send_cached(
+    tr_id: &AtomicU32,
+    ns_tx: &mpsc::Sender<Vec<u8>>,
+    internal_rx: &mut broadcast::Receiver<InternalEvent>,
+    token: &str,
+) -> Result<(), SdkError> {
+    send_ticket(tr_id, ns_tx, internal_rx, token, true).await
+}
+
+async fn send_ticket(
+    tr_id: &AtomicU32,
+    ns_tx: &mpsc::Sender<Vec<u8>>,
+    internal_rx: &mut broadcast::Receiver<InternalEvent>,
+    token: &str,
+    cached: bool,
 ) -> Result<(), SdkError> {
     tr_id.fetch_add(1, Ordering::SeqCst);
     let tr_id = tr_id.load(Ordering::SeqCst);
@@ -19,13 +41,13 @@ pub async fn send(
         .await
         .or(Err(SdkError::TransmittingError))?;
 
-    trace!("C: {command}");
+    trace!("C: {}", redact(&command));
 
     loop {
         if let InternalEvent::ServerReply(reply) =
             internal_rx.recv().await.or(Err(SdkError::ReceivingError))?
         {
-            trace!("S: {reply}");
+            trace!("S: {}", redact(&reply));
 
             let args: Vec<&str> = reply.split_ascii_whitespace().collect();
             match *args.first().unwrap_or(&"") {
@@ -40,6 +62,10 @@ pub async fn send(
                     return Err(SdkError::ServerError);
                 }
 
+                "911" if cached && *args.get(1).unwrap_or(&"") == tr_id.to_string() => {
+                    return Err(SdkError::TicketRejected);
+                }
+
                 "911" | "923" | "928" if *args.get(1).unwrap_or(&"") == tr_id.to_string() => {
                     return Err(SdkError::ServerIsBusy);
                 }
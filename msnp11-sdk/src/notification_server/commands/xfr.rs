@@ -1,7 +1,10 @@
 use crate::enums::internal_event::InternalEvent;
 use crate::errors::sdk_error::SdkError;
 use crate::models::user_data::UserData;
+use crate::protocol_log::ProtocolLogger;
+use crate::protocol_log::redact;
 use crate::switchboard_server::switchboard::Switchboard;
+use crate::transport::Connector;
 use log::trace;
 use std::sync::Arc;
 use std::sync::atomic::{AtomicU32, Ordering};
@@ -12,6 +15,8 @@ pub async fn send(
     ns_tx: &mpsc::Sender<Vec<u8>>,
     internal_rx: &mut broadcast::Receiver<InternalEvent>,
     user_data: Arc<RwLock<UserData>>,
+    connector: &Connector,
+    protocol_logger: &ProtocolLogger,
 ) -> Result<Switchboard, SdkError> {
     tr_id.fetch_add(1, Ordering::SeqCst);
     let tr_id = tr_id.load(Ordering::SeqCst);
@@ -22,13 +27,13 @@ pub async fn send(
         .await
         .or(Err(SdkError::TransmittingError))?;
 
-    trace!("C: {command}");
+    trace!("C: {}", redact(&command));
 
     loop {
         if let InternalEvent::ServerReply(reply) =
             internal_rx.recv().await.or(Err(SdkError::ReceivingError))?
         {
-            trace!("S: {reply}");
+            trace!("S: {}", redact(&reply));
 
             let args: Vec<&str> = reply.split_ascii_whitespace().collect();
             if *args.first().unwrap_or(&"") == "XFR"
@@ -40,7 +45,15 @@ pub async fn send(
                 if let Some(server) = server_and_port.next()
                     && let Some(port) = server_and_port.next()
                 {
-                    return Switchboard::new(server, port, cki_string, user_data).await;
+                    return Switchboard::new(
+                        server,
+                        port,
+                        cki_string,
+                        user_data,
+                        connector,
+                        protocol_logger,
+                    )
+                    .await;
                 }
             }
         }
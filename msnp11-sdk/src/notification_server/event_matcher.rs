@@ -1,13 +1,19 @@
+use crate::enums::add_notification_mode::AddNotificationMode;
 use crate::enums::event::Event;
 use crate::enums::internal_event::InternalEvent;
 use crate::enums::msnp_list::MsnpList;
 use crate::enums::msnp_status::MsnpStatus;
+use crate::enums::phone_property::PhoneProperty;
+use crate::enums::privacy_mode::PrivacyMode;
+use crate::models::client_capabilities::ClientCapabilities;
 use crate::models::personal_message::PersonalMessage;
 use crate::models::presence::Presence;
+use crate::models::profile::Profile;
+use crate::notification_server::notification::Notification;
 use core::str;
 use std::borrow::Cow;
 
-pub fn into_event(message: &Vec<u8>) -> Option<Event> {
+pub fn into_event(message: &Vec<u8>, last_contact: &mut Option<String>) -> Option<Event> {
     let reply = unsafe { str::from_utf8_unchecked(message.as_slice()) };
     let command = reply.lines().next().unwrap_or_default().to_string() + "\r\n";
 
@@ -17,7 +23,7 @@ pub fn into_event(message: &Vec<u8>) -> Option<Event> {
             if args.len() < 3
                 && let Some(gtc) = args.get(1)
             {
-                Some(Event::Gtc(gtc.to_string()))
+                AddNotificationMode::from_str(gtc).map(Event::Gtc)
             } else {
                 None
             }
@@ -27,7 +33,7 @@ pub fn into_event(message: &Vec<u8>) -> Option<Event> {
             if args.len() < 3
                 && let Some(blp) = args.get(1)
             {
-                Some(Event::Blp(blp.to_string()))
+                PrivacyMode::from_str(blp).map(Event::Blp)
             } else {
                 None
             }
@@ -43,11 +49,30 @@ pub fn into_event(message: &Vec<u8>) -> Option<Event> {
                         .unwrap_or(Cow::from(*display_name))
                         .to_string(),
                 ))
+            } else if args.len() < 4
+                && let Some(property) = args.get(1)
+            {
+                PhoneProperty::from_args(property, args.get(2).unwrap_or(&""))
+                    .map(Event::UserPhoneProperty)
             } else {
                 None
             }
         }
 
+        "BPR" => {
+            // Sent after LST while synchronizing, otherwise with the contact's email
+            let (email, property, value) = if args.len() > 3 || args.get(1)?.contains("@") {
+                (args.get(1)?.to_string(), args.get(2)?, args.get(3))
+            } else {
+                (last_contact.clone()?, args.get(1)?, args.get(2))
+            };
+
+            Some(Event::ContactPhoneProperty {
+                email,
+                property: PhoneProperty::from_args(property, value.unwrap_or(&""))?,
+            })
+        }
+
         "LSG" => {
             if let Some(email) = args.get(1)
                 && let Some(guid) = args.get(2)
@@ -65,6 +90,8 @@ pub fn into_event(message: &Vec<u8>) -> Option<Event> {
 
         "LST" => {
             let email = args.get(1)?;
+            *last_contact = Some(email.replace("N=", ""));
+
             let mut lists: Vec<MsnpList> = Vec::new();
             let lists_number_index = if args.len() > 4 { 4 } else { 3 };
 
@@ -151,7 +178,9 @@ pub fn into_event(message: &Vec<u8>) -> Option<Event> {
                     .to_string(),
                 presence: Presence {
                     status,
-                    client_id: args.get(5).unwrap_or(&"").parse().unwrap_or(0),
+                    client_id: ClientCapabilities::from_bits_retain(
+                        args.get(5).unwrap_or(&"").parse().unwrap_or(0),
+                    ),
                     msn_object: if let Some(msn_object) = &msn_object {
                         quick_xml::de::from_str(msn_object).ok()
                     } else {
@@ -187,7 +216,9 @@ pub fn into_event(message: &Vec<u8>) -> Option<Event> {
                     .to_string(),
                 presence: Presence {
                     status,
-                    client_id: args.get(4).unwrap_or(&"").parse().unwrap_or(0),
+                    client_id: ClientCapabilities::from_bits_retain(
+                        args.get(4).unwrap_or(&"").parse().unwrap_or(0),
+                    ),
                     msn_object: if let Some(msn_object) = &msn_object {
                         quick_xml::de::from_str(msn_object).ok()
                     } else {
@@ -205,7 +236,7 @@ pub fn into_event(message: &Vec<u8>) -> Option<Event> {
             let personal_message =
                 quick_xml::de::from_str(payload.as_str()).unwrap_or(PersonalMessage {
                     psm: "".to_string(),
-                    current_media: "".to_string(),
+                    current_media: None,
                 });
 
             Some(Event::PersonalMessageUpdate {
@@ -248,24 +279,73 @@ pub fn into_event(message: &Vec<u8>) -> Option<Event> {
 
         "MSG" => {
             let payload = reply.replace(&command, "");
-            let mut payload_lines = payload.lines();
-            let content_type = payload_lines.nth(1)?;
-
-            if content_type.contains("application/x-msmsgssystemmessage") {
-                let message_type = payload_lines.nth(1)?;
-                let (_, message_type) = message_type.split_once(":")?;
-                let message_type = message_type.trim();
-
-                if message_type == "1" {
-                    let time_remaining = payload_lines.next()?;
-                    let (_, time_remaining) = time_remaining.split_once(":")?;
-
-                    let time_remaining = time_remaining.trim().parse::<u32>().ok()?;
-                    return Some(Event::ServerMaintenanceScheduled { time_remaining });
+            let (headers, body) = payload.split_once("\r\n\r\n").unwrap_or((&payload, ""));
+
+            let content_type = header(headers, "Content-Type")?;
+            let content_type = content_type.split(";").next().unwrap_or_default().trim();
+
+            match content_type {
+                "application/x-msmsgssystemmessage" => {
+                    let message_type = header(body, "Type")?.parse::<u32>().ok()?;
+                    match message_type {
+                        1 => {
+                            let time_remaining = header(body, "Arg1")?.parse::<u32>().ok()?;
+                            Some(Event::ServerMaintenanceScheduled { time_remaining })
+                        }
+
+                        2 => Some(Event::ServerMaintenanceCancelled),
+                        _ => {
+                            let args = (1..)
+                                .map_while(|i| header(body, &format!("Arg{i}")))
+                                .map(str::to_string)
+                                .collect();
+
+                            Some(Event::SystemMessage { message_type, args })
+                        }
+                    }
                 }
-            }
 
-            None
+                "text/x-msmsgsprofile" => Some(Event::Profile(Profile {
+                    country: header(headers, "country").unwrap_or_default().to_string(),
+                    language: header(headers, "lang_preference")
+                        .unwrap_or_default()
+                        .parse()
+                        .unwrap_or(0),
+                    kid: header(headers, "Kid").unwrap_or_default() == "1",
+                    email_enabled: header(headers, "EmailEnabled").unwrap_or_default() == "1",
+                    mspauth: header(headers, "MSPAuth").unwrap_or_default().to_string(),
+                    client_ip: header(headers, "ClientIP").unwrap_or_default().to_string(),
+                    // Sent with its bytes swapped
+                    client_port: header(headers, "ClientPort")
+                        .unwrap_or_default()
+                        .parse::<u16>()
+                        .unwrap_or(0)
+                        .swap_bytes(),
+                })),
+
+                "text/x-msmsgsinitialemailnotification" => Some(Event::InboxStatus {
+                    unread: header(body, "Inbox-Unread")?.parse().ok()?,
+                    folders_unread: header(body, "Folders-Unread")
+                        .unwrap_or_default()
+                        .parse()
+                        .unwrap_or(0),
+                }),
+
+                "text/x-msmsgsemailnotification" => Some(Event::NewEmail {
+                    from: header(body, "From").unwrap_or_default().to_string(),
+                    from_address: header(body, "From-Addr").unwrap_or_default().to_string(),
+                    subject: header(body, "Subject").unwrap_or_default().to_string(),
+                    folder: header(body, "Dest-Folder").unwrap_or_default().to_string(),
+                }),
+
+                "text/x-msmsgsactivemailnotification" => Some(Event::MailboxActivity {
+                    source_folder: header(body, "Src-Folder").unwrap_or_default().to_string(),
+                    destination_folder: header(body, "Dest-Folder").unwrap_or_default().to_string(),
+                    message_delta: header(body, "Message-Delta")?.parse().ok()?,
+                }),
+
+                _ => None,
+            }
         }
 
         "OUT" => {
@@ -276,10 +356,45 @@ pub fn into_event(message: &Vec<u8>) -> Option<Event> {
             Some(Event::Disconnected)
         }
 
+        "NOT" => {
+            let payload = reply.replace(&command, "");
+            let notification: Notification = quick_xml::de::from_str(&payload).ok()?;
+
+            // Action URLs are relative to the site
+            let action_url = notification.message.action.map(|action| {
+                if action.url.starts_with("http") {
+                    action.url
+                } else {
+                    format!(
+                        "{}/{}",
+                        notification.site_url.trim_end_matches("/"),
+                        action.url.trim_start_matches("/")
+                    )
+                }
+            });
+
+            Some(Event::Alert {
+                id: notification.id,
+                site_url: notification.site_url,
+                action_url,
+                text: notification.message.body.text,
+            })
+        }
+
         _ => None,
     }
 }
 
+/// Returns the value of a MIME header or of a `Name: value` line in a message body.
+fn header<'a>(block: &'a str, name: &str) -> Option<&'a str> {
+    block.lines().find_map(|line| {
+        let (key, value) = line.split_once(":")?;
+        key.trim()
+            .eq_ignore_ascii_case(name)
+            .then_some(value.trim())
+    })
+}
+
 pub fn into_internal_event(message: &Vec<u8>) -> InternalEvent {
     let reply = unsafe { str::from_utf8_unchecked(message.as_slice()) }.to_string();
     let command = reply.lines().next().unwrap_or_default().to_string() + "\r\n";
@@ -304,6 +419,14 @@ pub fn into_internal_event(message: &Vec<u8>) -> InternalEvent {
             }
         }
 
+        "CHL" => {
+            if let Some(challenge) = args.get(2) {
+                InternalEvent::Challenge(challenge.to_string())
+            } else {
+                InternalEvent::ServerReply(reply)
+            }
+        }
+
         _ => InternalEvent::ServerReply(reply),
     }
 }
@@ -1,2 +1,5 @@
+pub mod challenge;
 pub mod commands;
 pub mod event_matcher;
+pub mod notification;
+pub mod privacy_manager;
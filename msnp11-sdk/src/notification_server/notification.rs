@@ -0,0 +1,33 @@
+use serde::Deserialize;
+
+/// Payload of a `NOT` command, sent by MSN Alerts.
+#[derive(Deserialize)]
+#[serde(rename = "NOTIFICATION")]
+pub(crate) struct Notification {
+    #[serde(rename = "@id")]
+    pub id: String,
+    #[serde(rename = "@siteurl")]
+    pub site_url: String,
+    #[serde(rename = "MSG")]
+    pub message: NotificationMessage,
+}
+
+#[derive(Deserialize)]
+pub(crate) struct NotificationMessage {
+    #[serde(rename = "ACTION")]
+    pub action: Option<NotificationUrl>,
+    #[serde(rename = "BODY")]
+    pub body: NotificationBody,
+}
+
+#[derive(Deserialize)]
+pub(crate) struct NotificationUrl {
+    #[serde(rename = "@url")]
+    pub url: String,
+}
+
+#[derive(Deserialize)]
+pub(crate) struct NotificationBody {
+    #[serde(rename = "TEXT")]
+    pub text: String,
+}
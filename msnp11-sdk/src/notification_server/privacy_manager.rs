@@ -0,0 +1,165 @@
+use crate::enums::event::Event;
+use crate::enums::msnp_list::MsnpList;
+use crate::enums::privacy_mode::PrivacyMode;
+use crate::errors::contact_error::ContactError;
+use crate::models::contact_request::ContactRequest;
+use std::collections::HashSet;
+
+/// Keeps track of the privacy mode and the allow and block lists, so contacts are never left in both lists, along
+/// with the contact requests still waiting for an answer.
+#[derive(Debug, Default)]
+pub(crate) struct PrivacyManager {
+    mode: Option<PrivacyMode>,
+    allow_list: HashSet<String>,
+    block_list: HashSet<String>,
+    pending_list: HashSet<String>,
+    requests: Vec<ContactRequest>,
+}
+
+/// Commands needed to move a contact to the allow or block list.
+pub(crate) struct PrivacyTransition {
+    pub(crate) remove_from: Option<MsnpList>,
+    pub(crate) add_to: Option<MsnpList>,
+}
+
+impl PrivacyManager {
+    /// Updates the lists from the ones sent while synchronizing and from reverse list changes.
+    pub(crate) fn handle_event(&mut self, event: &Event) {
+        match event {
+            Event::Blp(mode) => self.mode = Some(*mode),
+            Event::ContactInForwardList {
+                email,
+                display_name,
+                lists,
+                ..
+            }
+            | Event::Contact {
+                email,
+                display_name,
+                lists,
+            } => {
+                for list in lists {
+                    self.added(email, list);
+                }
+
+                // Contacts in the reverse list but in neither the allow or block list haven't been answered yet
+                if lists.contains(&MsnpList::PendingList)
+                    || (lists.contains(&MsnpList::ReverseList) && !self.is_listed(email))
+                {
+                    self.add_request(email, display_name);
+                }
+            }
+
+            // The server keeps unanswered contacts that added the user in the pending list
+            Event::AddedBy {
+                email,
+                display_name,
+            } if !self.is_listed(email) => {
+                self.pending_list.insert(email.to_string());
+                self.add_request(email, display_name);
+            }
+
+            Event::RemovedBy(email) => self.remove_request(email),
+            _ => (),
+        }
+    }
+
+    pub(crate) fn requests(&self) -> Vec<ContactRequest> {
+        self.requests.clone()
+    }
+
+    pub(crate) fn request(&self, email: &str) -> Option<&ContactRequest> {
+        self.requests.iter().find(|request| request.email == email)
+    }
+
+    pub(crate) fn is_in_pending_list(&self, email: &str) -> bool {
+        self.pending_list.contains(email)
+    }
+
+    pub(crate) fn remove_request(&mut self, email: &str) {
+        self.requests.retain(|request| request.email != email);
+    }
+
+    fn add_request(&mut self, email: &str, display_name: &str) {
+        if self.request(email).is_none() {
+            self.requests.push(ContactRequest {
+                email: email.to_string(),
+                display_name: display_name.to_string(),
+            });
+        }
+    }
+
+    fn is_listed(&self, email: &str) -> bool {
+        self.allow_list.contains(email) || self.block_list.contains(email)
+    }
+
+    pub(crate) fn mode(&self) -> Option<PrivacyMode> {
+        self.mode
+    }
+
+    pub(crate) fn set_mode(&mut self, mode: PrivacyMode) {
+        self.mode = Some(mode);
+    }
+
+    pub(crate) fn added(&mut self, email: &str, list: &MsnpList) {
+        match list {
+            MsnpList::AllowList => self.allow_list.insert(email.to_string()),
+            MsnpList::BlockList => self.block_list.insert(email.to_string()),
+            MsnpList::PendingList => self.pending_list.insert(email.to_string()),
+            _ => false,
+        };
+    }
+
+    pub(crate) fn removed(&mut self, email: &str, list: &MsnpList) {
+        match list {
+            MsnpList::AllowList => self.allow_list.remove(email),
+            MsnpList::BlockList => self.block_list.remove(email),
+            MsnpList::PendingList => self.pending_list.remove(email),
+            _ => false,
+        };
+    }
+
+    /// Whether a contact can see your presence and talk to you.
+    pub(crate) fn is_allowed(&self, email: &str) -> bool {
+        if self.block_list.contains(email) {
+            false
+        } else if self.allow_list.contains(email) {
+            true
+        } else {
+            self.mode != Some(PrivacyMode::BlockUnlisted)
+        }
+    }
+
+    /// Returns what needs to change to move a contact to the allow or block list, removing it from the opposite one
+    /// first since the server rejects contacts in both.
+    pub(crate) fn transition(
+        &self,
+        email: &str,
+        list: MsnpList,
+    ) -> Result<PrivacyTransition, ContactError> {
+        let (in_list, in_opposite, opposite) = match list {
+            MsnpList::AllowList => (
+                self.allow_list.contains(email),
+                self.block_list.contains(email),
+                MsnpList::BlockList,
+            ),
+
+            MsnpList::BlockList => (
+                self.block_list.contains(email),
+                self.allow_list.contains(email),
+                MsnpList::AllowList,
+            ),
+
+            _ => return Err(ContactError::InvalidArgument),
+        };
+
+        if in_list && !in_opposite {
+            return Err(ContactError::AlreadyInList);
+        }
+
+        Ok(PrivacyTransition {
+            remove_from: in_opposite.then_some(opposite),
+            add_to: (!in_list).then_some(list),
+        })
+    }
+}
@@ -0,0 +1,307 @@
+use crate::enums::internal_event::InternalEvent;
+use crate::enums::msnp_status::MsnpStatus;
+use crate::errors::sdk_error::SdkError;
+use crate::models::auto_away_settings::AutoAwaySettings;
+use crate::models::personal_message::PersonalMessage;
+use crate::models::presence::Presence;
+use crate::models::user_data::UserData;
+use crate::notification_server::commands::{chg, uux};
+use log::error;
+use std::sync::Arc;
+use std::sync::atomic::AtomicU32;
+use std::time::Duration;
+use tokio::sync::{Mutex, Notify, RwLock, broadcast, mpsc};
+use tokio::time::Instant;
+use tokio_util::sync::CancellationToken;
+
+/// How long to wait before changing the status again after the server failed to.
+const RETRY_INTERVAL: Duration = Duration::from_secs(10);
+
+/// Switches the user to `Idle` and then `Away` after a period without activity, restoring the previous status and
+/// personal message once [report_activity][PresenceManager::report_activity] is called. The status and personal
+/// message set through the attached [Client][crate::client::Client] are remembered and set again when the manager
+/// is attached to a new one, e.g. after reconnecting.
+#[derive(Clone)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Object))]
+pub struct PresenceManager {
+    settings: AutoAwaySettings,
+    state: Arc<Mutex<PresenceState>>,
+    activity: Arc<Notify>,
+}
+
+struct PresenceState {
+    status: Option<MsnpStatus>,
+    personal_message: Option<PersonalMessage>,
+    auto_status: Option<MsnpStatus>,
+    last_activity: Instant,
+    connection: Option<PresenceConnection>,
+}
+
+/// What the manager needs from a client to send commands.
+#[derive(Clone)]
+pub(crate) struct PresenceConnection {
+    pub(crate) tr_id: Arc<AtomicU32>,
+    pub(crate) ns_tx: mpsc::Sender<Vec<u8>>,
+    pub(crate) internal_tx: broadcast::Sender<InternalEvent>,
+    pub(crate) user_data: Arc<RwLock<UserData>>,
+    pub(crate) cancellation_token: CancellationToken,
+}
+
+impl PresenceConnection {
+    async fn set_presence(&self, status: MsnpStatus) -> Result<(), SdkError> {
+        let mut internal_rx = self.internal_tx.subscribe();
+        let user_data = self.user_data.read().await;
+        let presence = Presence::new_without_object(status, user_data.capabilities);
+
+        chg::send(
+            &self.tr_id,
+            &self.ns_tx,
+            &mut internal_rx,
+            &presence,
+            user_data.msn_object.as_deref(),
+        )
+        .await
+    }
+
+    async fn set_personal_message(
+        &self,
+        personal_message: &PersonalMessage,
+    ) -> Result<(), SdkError> {
+        let mut internal_rx = self.internal_tx.subscribe();
+        uux::send(&self.tr_id, &self.ns_tx, &mut internal_rx, personal_message).await
+    }
+}
+
+#[cfg_attr(feature = "uniffi", uniffi::export)]
+impl PresenceManager {
+    #[cfg_attr(feature = "uniffi", uniffi::constructor)]
+    pub fn new(settings: AutoAwaySettings) -> Self {
+        Self {
+            settings,
+            state: Arc::new(Mutex::new(PresenceState {
+                status: None,
+                personal_message: None,
+                auto_status: None,
+                last_activity: Instant::now(),
+                connection: None,
+            })),
+            activity: Arc::new(Notify::new()),
+        }
+    }
+
+    /// Resets the inactivity timer, restoring the previous status and personal message if idle or away.
+    pub async fn report_activity(&self) -> Result<(), SdkError> {
+        let (connection, status, personal_message) = {
+            let mut state = self.state.lock().await;
+            state.last_activity = Instant::now();
+
+            match &state.connection {
+                Some(connection) if state.auto_status.is_some() => (
+                    connection.clone(),
+                    state.status.clone(),
+                    state.personal_message.clone(),
+                ),
+
+                _ => {
+                    state.auto_status = None;
+                    self.activity.notify_one();
+                    return Ok(());
+                }
+            }
+        };
+
+        // Sent without holding the state, so a slow reply doesn't block the other methods
+        if let Some(status) = status {
+            connection.set_presence(status).await?;
+        }
+
+        if self.settings.away_message.is_some() {
+            let personal_message = personal_message.unwrap_or_else(|| PersonalMessage {
+                psm: "".to_string(),
+                current_media: None,
+            });
+
+            connection.set_personal_message(&personal_message).await?;
+        }
+
+        self.state.lock().await.auto_status = None;
+        self.activity.notify_one();
+        Ok(())
+    }
+
+    /// Returns the last status set through the client, not counting automatic changes.
+    pub async fn get_status(&self) -> Option<MsnpStatus> {
+        self.state.lock().await.status.clone()
+    }
+
+    /// Returns `Idle` or `Away` if the manager changed the status because of inactivity.
+    pub async fn get_auto_status(&self) -> Option<MsnpStatus> {
+        self.state.lock().await.auto_status.clone()
+    }
+}
+
+impl PresenceManager {
+    pub(crate) async fn set_status(&self, status: MsnpStatus) {
+        let mut state = self.state.lock().await;
+        state.status = Some(status);
+        state.auto_status = None;
+        state.last_activity = Instant::now();
+        self.activity.notify_one();
+    }
+
+    pub(crate) async fn set_personal_message(&self, personal_message: PersonalMessage) {
+        self.state.lock().await.personal_message = Some(personal_message);
+    }
+
+    /// Uses a new client connection, setting the remembered status and personal message again.
+    pub(crate) async fn attach(&self, connection: PresenceConnection) -> Result<(), SdkError> {
+        let (status, personal_message) = {
+            let mut state = self.state.lock().await;
+            if let Some(previous) = state.connection.take() {
+                previous.cancellation_token.cancel();
+            }
+
+            state.auto_status = None;
+            state.last_activity = Instant::now();
+
+            // What was set on the client before attaching is already in effect there
+            let user_data = connection.user_data.read().await;
+            let status = state.status.clone();
+            if status.is_none() {
+                state.status = user_data.status.clone();
+            }
+
+            let personal_message = state.personal_message.clone();
+            if personal_message.is_none() {
+                state.personal_message = user_data.personal_message.clone();
+            }
+
+            (status, personal_message)
+        };
+
+        if let Some(status) = status {
+            connection.set_presence(status).await?;
+        }
+
+        if let Some(personal_message) = &personal_message {
+            connection.set_personal_message(personal_message).await?;
+        }
+
+        let cancellation_token = connection.cancellation_token.clone();
+        self.state.lock().await.connection = Some(connection);
+
+        let manager = self.clone();
+        tokio::spawn(async move {
+            let mut retry_at = None;
+            loop {
+                let deadline = manager.next_deadline().await.map(|deadline| {
+                    retry_at.map_or(deadline, |retry_at: Instant| deadline.max(retry_at))
+                });
+
+                tokio::select! {
+                    _ = async {
+                        match deadline {
+                            Some(deadline) => tokio::time::sleep_until(deadline).await,
+                            None => std::future::pending().await,
+                        }
+                    } => {
+                        retry_at = None;
+                        if let Err(error) = manager.check_inactivity().await {
+                            error!("{error}");
+                            retry_at = Some(Instant::now() + RETRY_INTERVAL);
+                        }
+                    }
+
+                    _ = manager.activity.notified() => (),
+                    _ = cancellation_token.cancelled() => break,
+                }
+            }
+        });
+
+        Ok(())
+    }
+
+    pub(crate) async fn detach(&self) {
+        if let Some(connection) = self.state.lock().await.connection.take() {
+            connection.cancellation_token.cancel();
+        }
+    }
+
+    /// Returns when the status should change next, only while the user is online.
+    async fn next_deadline(&self) -> Option<Instant> {
+        let state = self.state.lock().await;
+        if state.status != Some(MsnpStatus::Online) {
+            return None;
+        }
+
+        match state.auto_status {
+            None => Some(state.last_activity + self.settings.idle_after),
+            Some(MsnpStatus::Idle) => self
+                .settings
+                .away_after
+                .map(|away_after| state.last_activity + away_after),
+            _ => None,
+        }
+    }
+
+    async fn check_inactivity(&self) -> Result<(), SdkError> {
+        let (connection, status, was_active, last_activity) = {
+            let mut state = self.state.lock().await;
+            if state.status != Some(MsnpStatus::Online) {
+                return Ok(());
+            }
+
+            let inactive_for = state.last_activity.elapsed();
+            let status = if self
+                .settings
+                .away_after
+                .is_some_and(|away_after| inactive_for >= away_after)
+            {
+                MsnpStatus::Away
+            } else if inactive_for >= self.settings.idle_after {
+                MsnpStatus::Idle
+            } else {
+                return Ok(());
+            };
+
+            if state.auto_status.as_ref() == Some(&status) {
+                return Ok(());
+            }
+
+            let Some(connection) = state.connection.clone() else {
+                state.auto_status = Some(status);
+                return Ok(());
+            };
+
+            (
+                connection,
+                status,
+                state.auto_status.is_none(),
+                state.last_activity,
+            )
+        };
+
+        // Sent without holding the state, so a slow reply doesn't block the other methods
+        connection.set_presence(status.clone()).await?;
+        if was_active && let Some(away_message) = &self.settings.away_message {
+            connection.set_personal_message(away_message).await?;
+        }
+
+        let active_again = {
+            let mut state = self.state.lock().await;
+            if state.status != Some(MsnpStatus::Online) {
+                return Ok(());
+            }
+
+            state.auto_status = Some(status);
+            state.last_activity != last_activity
+        };
+
+        // Activity reported while the status was changing restores it right away
+        if active_again {
+            self.report_activity().await?;
+        }
+
+        Ok(())
+    }
+}
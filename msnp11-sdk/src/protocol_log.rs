@@ -0,0 +1,84 @@
+use crate::enums::protocol_direction::ProtocolDirection;
+use crate::enums::server_type::ServerType;
+use crate::models::protocol_log_entry::ProtocolLogEntry;
+use std::fmt;
+use std::sync::{Arc, RwLock};
+
+type Handler = Arc<dyn Fn(ProtocolLogEntry) + Send + Sync>;
+
+/// Replaces tickets, authentication and CKI strings in the first line of a command, so it can be logged. Payloads are
+/// replaced with their length, since some hold credentials, like `MSPAuth` in the profile message.
+pub(crate) fn redact(message: &str) -> String {
+    let (line, rest) = message.split_at(message.find("\r\n").unwrap_or(message.len()));
+    let mut args: Vec<&str> = line.split(' ').collect();
+
+    let secret = match args.as_slice() {
+        ["USR", _, "TWN", "S", ..] => Some(4),
+        // Switchboard login
+        ["USR", _, _, _] => Some(3),
+        ["ANS", ..] => Some(3),
+        ["XFR", _, "SB", _, "CKI", ..] => Some(5),
+        ["RNG", _, _, "CKI", ..] => Some(4),
+        _ => None,
+    };
+
+    if let Some(secret) = secret
+        && let Some(arg) = args.get_mut(secret)
+    {
+        *arg = "[redacted]";
+    }
+
+    let line = args.join(" ");
+    let payload = rest.strip_prefix("\r\n").unwrap_or(rest);
+    if payload.is_empty() {
+        line + rest
+    } else {
+        format!("{line} [{} byte payload]", payload.len())
+    }
+}
+
+/// Holds the protocol log handler, shared by the Notification Server connection and every Switchboard.
+#[derive(Clone, Default)]
+pub(crate) struct ProtocolLogger {
+    handler: Arc<RwLock<Option<Handler>>>,
+}
+
+impl ProtocolLogger {
+    pub(crate) fn set_handler(&self, handler: Handler) {
+        if let Ok(mut current) = self.handler.write() {
+            *current = Some(handler);
+        }
+    }
+
+    pub(crate) fn log(
+        &self,
+        direction: ProtocolDirection,
+        server_type: ServerType,
+        message: &[u8],
+    ) {
+        let Some(handler) = self.handler.read().ok().and_then(|handler| handler.clone()) else {
+            return;
+        };
+
+        let line_end = message
+            .windows(2)
+            .position(|window| window == b"\r\n")
+            .unwrap_or(message.len());
+
+        let command = String::from_utf8_lossy(&message[..line_end]);
+        let payload_length = message.len().saturating_sub(line_end + 2);
+
+        handler(ProtocolLogEntry {
+            direction,
+            server_type,
+            command: redact(&command),
+            payload_length: payload_length.try_into().unwrap_or(u32::MAX),
+        });
+    }
+}
+
+impl fmt::Debug for ProtocolLogger {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ProtocolLogger").finish_non_exhaustive()
+    }
+}
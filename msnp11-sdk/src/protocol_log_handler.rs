@@ -0,0 +1,8 @@
+use crate::models::protocol_log_entry::ProtocolLogEntry;
+
+/// This trait is used to define a protocol log handler when using this SDK through foreign language bindings. If using it with
+/// Rust the preferred handling method is closures.
+#[uniffi::export(with_foreign)]
+pub trait ProtocolLogHandler: Send + Sync {
+    fn log(&self, entry: ProtocolLogEntry);
+}
@@ -1,11 +1,10 @@
 use crate::errors::sdk_error::SdkError;
 use core::str;
-use tokio::io::AsyncReadExt;
-use tokio::net::tcp::OwnedReadHalf;
+use tokio::io::{AsyncRead, AsyncReadExt};
 use tokio_util::sync::CancellationToken;
 
 pub(crate) async fn receive_split(
-    rd: &mut OwnedReadHalf,
+    rd: &mut (impl AsyncRead + Unpin),
     cancellation_token: CancellationToken,
 ) -> Result<Vec<Vec<u8>>, SdkError> {
     let mut buf = vec![0; 1664];
@@ -37,8 +36,9 @@ pub(crate) async fn receive_split(
         let command = args.next().unwrap_or("");
 
         match command {
-            "GCF" | "UBX" | "MSG" => {
+            "GCF" | "UBX" | "MSG" | "NOT" => {
                 let length_index = match command {
+                    "NOT" => 0,
                     "UBX" => 1,
                     _ => 2,
                 };
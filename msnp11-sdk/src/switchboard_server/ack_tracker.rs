@@ -0,0 +1,143 @@
+use crate::enums::event::Event;
+use crate::models::message_handle::MessageHandle;
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+/// How long a message sent without `ACK`s is kept, waiting for a possible `NAK`.
+const NAK_WINDOW: Duration = Duration::from_secs(60);
+
+#[derive(Debug)]
+struct PendingMessage {
+    remaining: usize,
+    acknowledged: bool,
+    sent_at: Instant,
+}
+
+/// Matches `ACK` and `NAK` replies with messages sent without waiting for them.
+#[derive(Debug, Default)]
+pub(crate) struct AckTracker {
+    tr_ids: HashMap<u32, MessageHandle>,
+    messages: HashMap<MessageHandle, PendingMessage>,
+}
+
+impl AckTracker {
+    /// Starts tracking a message sent as the MSGs with these transaction IDs. `acknowledged` is whether the server
+    /// replies to successful deliveries.
+    pub(crate) fn register(&mut self, handle: MessageHandle, tr_ids: &[u32], acknowledged: bool) {
+        self.expire(Instant::now());
+
+        for tr_id in tr_ids {
+            self.tr_ids.insert(*tr_id, handle);
+        }
+
+        self.messages.insert(
+            handle,
+            PendingMessage {
+                remaining: tr_ids.len(),
+                acknowledged,
+                sent_at: Instant::now(),
+            },
+        );
+    }
+
+    /// Stops tracking a message, such as one that couldn't be sent.
+    pub(crate) fn unregister(&mut self, handle: MessageHandle) {
+        self.tr_ids.retain(|_, other| *other != handle);
+        self.messages.remove(&handle);
+    }
+
+    /// Forgets the messages without `ACK`s that weren't refused within [NAK_WINDOW], since they were delivered.
+    fn expire(&mut self, now: Instant) {
+        let expired: Vec<MessageHandle> = self
+            .messages
+            .iter()
+            .filter(|(_, message)| {
+                !message.acknowledged && now.duration_since(message.sent_at) >= NAK_WINDOW
+            })
+            .map(|(handle, _)| *handle)
+            .collect();
+
+        for handle in expired {
+            self.unregister(handle);
+        }
+    }
+
+    /// Returns a delivery event if the reply completes or fails a tracked message.
+    pub(crate) fn handle_reply(&mut self, reply: &[u8]) -> Option<Event> {
+        let line_end = reply
+            .windows(2)
+            .position(|window| window == b"\r\n")
+            .unwrap_or(reply.len());
+
+        let line = str::from_utf8(&reply[..line_end]).ok()?;
+        let args: Vec<&str> = line.split_ascii_whitespace().collect();
+        let tr_id = args.get(1)?.parse::<u32>().ok()?;
+
+        match *args.first()? {
+            "ACK" => {
+                let handle = self.tr_ids.remove(&tr_id)?;
+                let message = self.messages.get_mut(&handle)?;
+
+                message.remaining -= 1;
+                if message.remaining > 0 {
+                    return None;
+                }
+
+                self.messages.remove(&handle);
+                Some(Event::MessageDelivered { handle })
+            }
+
+            "NAK" | "282" => {
+                let handle = self.tr_ids.remove(&tr_id)?;
+                self.unregister(handle);
+
+                Some(Event::MessageFailed { handle })
+            }
+
+            _ => None,
+        }
+    }
+
+    /// Returns failure events for the messages still waiting for an `ACK` when the session ends.
+    pub(crate) fn fail_pending(&mut self) -> Vec<Event> {
+        self.tr_ids.clear();
+        self.messages
+            .drain()
+            .filter(|(_, message)| message.acknowledged)
+            .map(|(handle, _)| Event::MessageFailed { handle })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn negative_messages_expire() {
+        let mut tracker = AckTracker::default();
+        tracker.register(MessageHandle { id: 1 }, &[1, 2], false);
+        tracker.register(MessageHandle { id: 3 }, &[3], true);
+
+        tracker.expire(Instant::now());
+        assert_eq!(tracker.messages.len(), 2);
+
+        tracker.expire(Instant::now() + NAK_WINDOW);
+        assert_eq!(tracker.tr_ids.len(), 1);
+        assert!(tracker.handle_reply(b"NAK 1\r\n").is_none());
+        assert!(matches!(
+            tracker.handle_reply(b"ACK 3\r\n"),
+            Some(Event::MessageDelivered { handle }) if handle.id == 3
+        ));
+    }
+
+    #[test]
+    fn unregister_failed_message() {
+        let mut tracker = AckTracker::default();
+        tracker.register(MessageHandle { id: 1 }, &[1, 2], true);
+        tracker.unregister(MessageHandle { id: 1 });
+
+        assert!(tracker.handle_reply(b"ACK 1\r\n").is_none());
+        assert!(tracker.fail_pending().is_empty());
+    }
+}
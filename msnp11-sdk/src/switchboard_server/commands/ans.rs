@@ -1,5 +1,6 @@
 use crate::enums::internal_event::InternalEvent;
 use crate::errors::sdk_error::SdkError;
+use crate::protocol_log::redact;
 use log::trace;
 use std::error::Error;
 use std::sync::atomic::{AtomicU32, Ordering};
@@ -12,28 +13,35 @@ pub async fn send(
     email: &str,
     cki_string: &str,
     session_id: &str,
-) -> Result<(), Box<dyn Error + Send + Sync>> {
+) -> Result<Vec<String>, Box<dyn Error + Send + Sync>> {
     tr_id.fetch_add(1, Ordering::SeqCst);
     let tr_id = tr_id.load(Ordering::SeqCst);
 
     let command = format!("ANS {tr_id} {email} {cki_string} {session_id}\r\n");
     sb_tx.send(command.as_bytes().to_vec()).await?;
 
-    trace!("C: {command}");
+    trace!("C: {}", redact(&command));
 
+    let mut participants = Vec::new();
     loop {
         if let InternalEvent::ServerReply(reply) =
             internal_rx.recv().await.or(Err(SdkError::ReceivingError))?
         {
-            trace!("S: {reply}");
+            trace!("S: {}", redact(&reply));
 
             let args: Vec<&str> = reply.split_ascii_whitespace().collect();
             match *args.first().unwrap_or(&"") {
+                "IRO" if *args.get(1).unwrap_or(&"") == tr_id.to_string() => {
+                    if let Some(email) = args.get(4) {
+                        participants.push(email.to_string());
+                    }
+                }
+
                 "ANS"
                     if *args.get(1).unwrap_or(&"") == tr_id.to_string()
                         && *args.get(2).unwrap_or(&"") == "OK" =>
                 {
-                    return Ok(());
+                    return Ok(participants);
                 }
 
                 "911" if *args.get(1).unwrap_or(&"") == tr_id.to_string() => {
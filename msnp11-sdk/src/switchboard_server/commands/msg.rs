@@ -1,127 +1,233 @@
+use crate::enums::ack_mode::AckMode;
 use crate::enums::internal_event::InternalEvent;
 use crate::errors::messaging_error::MessagingError;
 use crate::errors::p2p_error::P2pError;
+use crate::models::message_handle::MessageHandle;
+use crate::models::mime_header::MimeHeader;
 use crate::models::plain_text::PlainText;
+use crate::protocol_log::redact;
+use crate::switchboard_server::ack_tracker::AckTracker;
+use crate::switchboard_server::message_chunks;
 use log::trace;
 use std::sync::atomic::{AtomicU32, Ordering};
-use tokio::sync::{broadcast, mpsc};
+use tokio::sync::{Mutex, broadcast, mpsc};
 
 pub async fn send_text_message(
     tr_id: &AtomicU32,
     sb_tx: &mpsc::Sender<Vec<u8>>,
     internal_rx: &mut broadcast::Receiver<InternalEvent>,
     message: &PlainText,
+    ack_mode: AckMode,
 ) -> Result<(), MessagingError> {
-    let payload = message.payload();
+    let payloads = text_payloads(message)?;
+    send_payloads(tr_id, sb_tx, internal_rx, payloads, ack_mode).await
+}
+
+pub async fn queue_text_message(
+    tr_id: &AtomicU32,
+    sb_tx: &mpsc::Sender<Vec<u8>>,
+    ack_tracker: &Mutex<AckTracker>,
+    message: &PlainText,
+    ack_mode: AckMode,
+) -> Result<MessageHandle, MessagingError> {
+    let payloads = text_payloads(message)?;
+    queue_payloads(tr_id, sb_tx, ack_tracker, payloads, ack_mode).await
+}
+
+pub async fn send_nudge(
+    tr_id: &AtomicU32,
+    sb_tx: &mpsc::Sender<Vec<u8>>,
+    internal_rx: &mut broadcast::Receiver<InternalEvent>,
+    ack_mode: AckMode,
+) -> Result<(), MessagingError> {
+    send_payloads(tr_id, sb_tx, internal_rx, vec![nudge_payload()], ack_mode).await
+}
+
+pub async fn queue_nudge(
+    tr_id: &AtomicU32,
+    sb_tx: &mpsc::Sender<Vec<u8>>,
+    ack_tracker: &Mutex<AckTracker>,
+    ack_mode: AckMode,
+) -> Result<MessageHandle, MessagingError> {
+    queue_payloads(tr_id, sb_tx, ack_tracker, vec![nudge_payload()], ack_mode).await
+}
+
+pub async fn send_typing_user(
+    tr_id: &AtomicU32,
+    sb_tx: &mpsc::Sender<Vec<u8>>,
+    email: &str,
+) -> Result<(), MessagingError> {
+    let mut payload = String::from("MIME-Version: 1.0\r\n");
+    payload.push_str("Content-Type: text/x-msmsgscontrol\r\n");
+    payload.push_str(format!("TypingUser: {email}\r\n\r\n\r\n").as_str());
 
     tr_id.fetch_add(1, Ordering::SeqCst);
     let tr_id = tr_id.load(Ordering::SeqCst);
 
-    let command = format!("MSG {tr_id} A {}\r\n{payload}", payload.len());
+    let command = format!("MSG {tr_id} U {}\r\n{payload}", payload.len());
     sb_tx
         .send(command.as_bytes().to_vec())
         .await
         .or(Err(MessagingError::TransmittingError))?;
 
-    trace!("C: {command}");
+    trace!("C: {}", redact(&command));
 
-    loop {
-        if let InternalEvent::ServerReply(reply) = internal_rx
-            .recv()
-            .await
-            .or(Err(MessagingError::ReceivingError))?
-        {
-            trace!("S: {reply}");
+    Ok(())
+}
 
-            let args: Vec<&str> = reply.split_ascii_whitespace().collect();
-            match *args.first().unwrap_or(&"") {
-                "ACK" if *args.get(1).unwrap_or(&"") == tr_id.to_string() => {
-                    return Ok(());
-                }
+pub async fn send_raw(
+    tr_id: &AtomicU32,
+    sb_tx: &mpsc::Sender<Vec<u8>>,
+    internal_rx: &mut broadcast::Receiver<InternalEvent>,
+    content_type: &str,
+    headers: &[MimeHeader],
+    body: &[u8],
+    ack_mode: AckMode,
+) -> Result<(), MessagingError> {
+    let has_line_break = |value: &str| value.contains(['\r', '\n']);
+    if has_line_break(content_type)
+        || headers
+            .iter()
+            .any(|header| has_line_break(&header.name) || has_line_break(&header.value))
+    {
+        return Err(MessagingError::InvalidHeaders);
+    }
 
-                "NAK" if *args.get(1).unwrap_or(&"") == tr_id.to_string() => {
-                    return Err(MessagingError::MessageNotDelivered);
-                }
+    // Received messages are parsed as text, so bodies are limited to UTF-8
+    if str::from_utf8(body).is_err() {
+        return Err(MessagingError::InvalidBody);
+    }
 
-                "282" if *args.get(1).unwrap_or(&"") == tr_id.to_string() => {
-                    return Err(MessagingError::MessageNotDelivered);
-                }
+    let mut payload = String::from("MIME-Version: 1.0\r\n");
+    payload.push_str(format!("Content-Type: {content_type}\r\n").as_str());
 
-                _ => (),
-            }
-        }
+    for header in headers {
+        payload.push_str(format!("{}: {}\r\n", header.name, header.value).as_str());
     }
+
+    payload.push_str("\r\n");
+    let mut payload = payload.as_bytes().to_vec();
+    payload.extend_from_slice(body);
+
+    if payload.len() > message_chunks::MAX_PAYLOAD_SIZE {
+        return Err(MessagingError::MessageTooLong);
+    }
+
+    send_payloads(tr_id, sb_tx, internal_rx, vec![payload], ack_mode).await
 }
 
-pub async fn send_nudge(
-    tr_id: &AtomicU32,
-    sb_tx: &mpsc::Sender<Vec<u8>>,
-    internal_rx: &mut broadcast::Receiver<InternalEvent>,
-) -> Result<(), MessagingError> {
+fn text_payloads(message: &PlainText) -> Result<Vec<Vec<u8>>, MessagingError> {
+    let chunks = message_chunks::split(&message.payload()).ok_or(MessagingError::MessageTooLong)?;
+    Ok(chunks.into_iter().map(String::into_bytes).collect())
+}
+
+fn nudge_payload() -> Vec<u8> {
     let mut payload = String::from("MIME-Version: 1.0\r\n");
     payload.push_str("Content-Type: text/x-msnmsgr-datacast\r\n\r\n");
     payload.push_str("ID: 1\r\n\r\n");
+    payload.into_bytes()
+}
 
-    tr_id.fetch_add(1, Ordering::SeqCst);
-    let tr_id = tr_id.load(Ordering::SeqCst);
+async fn transmit(
+    tr_id: u32,
+    sb_tx: &mpsc::Sender<Vec<u8>>,
+    payload: &[u8],
+    ack_mode: AckMode,
+) -> Result<(), MessagingError> {
+    let command_string = format!("MSG {tr_id} {} {}\r\n", ack_mode.as_str(), payload.len());
+    let mut command = command_string.as_bytes().to_vec();
+    command.extend_from_slice(payload);
 
-    let command = format!("MSG {tr_id} A {}\r\n{payload}", payload.len());
     sb_tx
-        .send(command.as_bytes().to_vec())
+        .send(command)
         .await
         .or(Err(MessagingError::TransmittingError))?;
 
-    trace!("C: {command}");
-
-    loop {
-        if let InternalEvent::ServerReply(reply) = internal_rx
-            .recv()
-            .await
-            .or(Err(MessagingError::ReceivingError))?
-        {
-            trace!("S: {reply}");
+    trace!("C: {}", redact(&command_string));
+    Ok(())
+}
 
-            let args: Vec<&str> = reply.split_ascii_whitespace().collect();
-            match *args.first().unwrap_or(&"") {
-                "ACK" if *args.get(1).unwrap_or(&"") == tr_id.to_string() => {
-                    return Ok(());
-                }
+/// Sends each payload in a MSG, waiting for its acknowledgement before the next one if the mode has one.
+async fn send_payloads(
+    tr_id: &AtomicU32,
+    sb_tx: &mpsc::Sender<Vec<u8>>,
+    internal_rx: &mut broadcast::Receiver<InternalEvent>,
+    payloads: Vec<Vec<u8>>,
+    ack_mode: AckMode,
+) -> Result<(), MessagingError> {
+    for payload in payloads {
+        tr_id.fetch_add(1, Ordering::SeqCst);
+        let tr_id = tr_id.load(Ordering::SeqCst);
 
-                "NAK" if *args.get(1).unwrap_or(&"") == tr_id.to_string() => {
-                    return Err(MessagingError::MessageNotDelivered);
-                }
+        transmit(tr_id, sb_tx, &payload, ack_mode).await?;
+        if !ack_mode.is_acknowledged() {
+            continue;
+        }
 
-                "282" if *args.get(1).unwrap_or(&"") == tr_id.to_string() => {
-                    return Err(MessagingError::MessageNotDelivered);
+        loop {
+            if let InternalEvent::ServerReply(reply) = internal_rx
+                .recv()
+                .await
+                .or(Err(MessagingError::ReceivingError))?
+            {
+                trace!("S: {}", redact(&reply));
+
+                let args: Vec<&str> = reply.split_ascii_whitespace().collect();
+                match *args.first().unwrap_or(&"") {
+                    "ACK" if *args.get(1).unwrap_or(&"") == tr_id.to_string() => {
+                        break;
+                    }
+
+                    "NAK" if *args.get(1).unwrap_or(&"") == tr_id.to_string() => {
+                        return Err(MessagingError::MessageNotDelivered);
+                    }
+
+                    "282" if *args.get(1).unwrap_or(&"") == tr_id.to_string() => {
+                        return Err(MessagingError::MessageNotDelivered);
+                    }
+
+                    _ => (),
                 }
-
-                _ => (),
             }
         }
     }
+
+    Ok(())
 }
 
-pub async fn send_typing_user(
+/// Sends each payload in a MSG without waiting. Replies are matched by the [AckTracker] instead.
+async fn queue_payloads(
     tr_id: &AtomicU32,
     sb_tx: &mpsc::Sender<Vec<u8>>,
-    email: &str,
-) -> Result<(), MessagingError> {
-    let mut payload = String::from("MIME-Version: 1.0\r\n");
-    payload.push_str("Content-Type: text/x-msmsgscontrol\r\n");
-    payload.push_str(format!("TypingUser: {email}\r\n\r\n\r\n").as_str());
-
-    tr_id.fetch_add(1, Ordering::SeqCst);
-    let tr_id = tr_id.load(Ordering::SeqCst);
-
-    let command = format!("MSG {tr_id} U {}\r\n{payload}", payload.len());
-    sb_tx
-        .send(command.as_bytes().to_vec())
-        .await
-        .or(Err(MessagingError::TransmittingError))?;
+    ack_tracker: &Mutex<AckTracker>,
+    payloads: Vec<Vec<u8>>,
+    ack_mode: AckMode,
+) -> Result<MessageHandle, MessagingError> {
+    let tr_ids: Vec<u32> = payloads
+        .iter()
+        .map(|_| tr_id.fetch_add(1, Ordering::SeqCst) + 1)
+        .collect();
+
+    let handle = MessageHandle {
+        id: *tr_ids.first().ok_or(MessagingError::TransmittingError)?,
+    };
+
+    // Registered first, so a fast reply isn't missed
+    if ack_mode != AckMode::Unacknowledged {
+        ack_tracker
+            .lock()
+            .await
+            .register(handle, &tr_ids, ack_mode.is_acknowledged());
+    }
 
-    trace!("C: {command}");
+    for (tr_id, payload) in tr_ids.into_iter().zip(payloads) {
+        if let Err(error) = transmit(tr_id, sb_tx, &payload, ack_mode).await {
+            ack_tracker.lock().await.unregister(handle);
+            return Err(error);
+        }
+    }
 
-    Ok(())
+    Ok(handle)
 }
 
 pub async fn send_p2p(
@@ -150,13 +256,13 @@ pub async fn send_p2p(
         .await
         .or(Err(P2pError::TransmittingError))?;
 
-    trace!("C: {command_string}");
+    trace!("C: {}", redact(&command_string));
 
     loop {
         if let InternalEvent::ServerReply(reply) =
             internal_rx.recv().await.or(Err(P2pError::ReceivingError))?
         {
-            trace!("S: {reply}");
+            trace!("S: {}", redact(&reply));
 
             let args: Vec<&str> = reply.split_ascii_whitespace().collect();
             match *args.first().unwrap_or(&"") {
@@ -0,0 +1,70 @@
+use crate::enums::event::Event;
+use crate::errors::sdk_error::SdkError;
+use std::sync::Mutex;
+
+/// Sends a copy of every event to each handler, and for a Switchboard to the conversations using the session. Events sent
+/// before anyone subscribed are kept for the first subscriber, so none are lost while answering an invitation.
+#[derive(Debug, Default)]
+pub(crate) struct EventFanout {
+    state: Mutex<FanoutState>,
+}
+
+#[derive(Debug, Default)]
+struct FanoutState {
+    subscribers: Vec<async_channel::Sender<Event>>,
+    backlog: Vec<Event>,
+    closed: bool,
+}
+
+impl EventFanout {
+    pub(crate) fn subscribe(&self) -> async_channel::Receiver<Event> {
+        let (event_tx, event_rx) = async_channel::unbounded();
+        let Ok(mut state) = self.state.lock() else {
+            return event_rx;
+        };
+
+        for event in state.backlog.drain(..) {
+            let _ = event_tx.try_send(event);
+        }
+
+        if state.closed {
+            event_tx.close();
+        } else {
+            state.subscribers.push(event_tx);
+        }
+
+        event_rx
+    }
+
+    /// Sends an event to every subscriber still listening. Fails only once the session's events were closed.
+    pub(crate) fn send(&self, event: Event) -> Result<(), SdkError> {
+        let Ok(mut state) = self.state.lock() else {
+            return Err(SdkError::Disconnected);
+        };
+
+        if state.closed {
+            return Err(SdkError::Disconnected);
+        }
+
+        if state.subscribers.is_empty() {
+            state.backlog.push(event);
+            return Ok(());
+        }
+
+        state
+            .subscribers
+            .retain(|subscriber| subscriber.try_send(event.clone()).is_ok());
+
+        Ok(())
+    }
+
+    /// Ends every subscription once the events already sent are received.
+    pub(crate) fn close(&self) {
+        if let Ok(mut state) = self.state.lock() {
+            state.closed = true;
+            for subscriber in state.subscribers.drain(..) {
+                subscriber.close();
+            }
+        }
+    }
+}
@@ -1,6 +1,10 @@
 use crate::enums::event::Event;
 use crate::enums::internal_event::InternalEvent;
+use crate::mime::MimeMessage;
+use crate::models::client_capabilities::ClientCapabilities;
+use crate::models::custom_emoticon::CustomEmoticon;
 use crate::models::plain_text::PlainText;
+use crate::switchboard_server::message_chunks::MessageChunks;
 use crate::switchboard_server::p2p::binary_header::BinaryHeader;
 #[cfg(feature = "file-transfers")]
 use crate::switchboard_server::p2p::file_context::FileContext;
@@ -10,62 +14,124 @@ use core::str;
 use deku::DekuContainerRead;
 use std::io::Cursor;
 
-pub fn into_event(message: &Vec<u8>) -> Option<Event> {
-    let reply = unsafe { str::from_utf8_unchecked(message.as_slice()) };
+pub fn into_event(message: &Vec<u8>, chunks: &mut MessageChunks) -> Option<Event> {
+    // Binary P2P messages are handled through internal events, anything else must be text
+    let reply = str::from_utf8(message.as_slice()).ok()?;
     let command = reply.lines().next().unwrap_or_default().to_string() + "\r\n";
 
     let args: Vec<&str> = command.split_ascii_whitespace().collect();
     match *args.first().unwrap_or(&"") {
         "MSG" => {
             let payload = reply.replace(command.as_str(), "");
-            let content_type = payload.lines().nth(1)?;
+            let payload = chunks.add(args.get(1)?, payload)?;
+            let mime_message = MimeMessage::parse(&payload);
+            let content_type = mime_message.header("Content-Type")?;
 
             if content_type.contains("text/plain")
                 && let Some(email) = args.get(1)
             {
                 return Some(Event::TextMessage {
                     email: email.to_string(),
-                    message: PlainText::new(payload),
+                    message: PlainText::new(&payload),
                 });
             }
 
-            if content_type.contains("text/x-msnmsgr-datacast") {
-                let text = payload.split("\r\n\r\n").nth(1).unwrap_or_default();
-                if text == "ID: 1"
-                    && let Some(email) = args.get(1)
-                {
-                    return Some(Event::Nudge {
-                        email: email.to_string(),
-                    });
-                }
+            if content_type.contains("text/x-msnmsgr-datacast")
+                && mime_message.body.lines().next() == Some("ID: 1")
+                && let Some(email) = args.get(1)
+            {
+                return Some(Event::Nudge {
+                    email: email.to_string(),
+                });
+            }
+
+            if (content_type.contains("text/x-mms-emoticon")
+                || content_type.contains("text/x-mms-animemoticon"))
+                && let Some(email) = args.get(1)
+            {
+                let fields: Vec<&str> = mime_message.body.split('\t').collect();
+                let emoticons = fields
+                    .chunks_exact(2)
+                    .map(|emoticon| CustomEmoticon {
+                        shortcut: emoticon[0].to_string(),
+                        msn_object: emoticon[1].to_string(),
+                    })
+                    .collect();
+
+                return Some(Event::CustomEmoticons {
+                    email: email.to_string(),
+                    emoticons,
+                });
             }
 
             if content_type.contains("text/x-msmsgscontrol") {
-                let typing_user = payload.lines().nth(2)?;
                 return Some(Event::TypingNotification {
-                    email: typing_user.replace("TypingUser: ", ""),
+                    email: mime_message.header("TypingUser")?.to_string(),
                 });
             }
 
-            None
-        }
+            // Handled through internal events
+            if content_type.contains("application/x-msnmsgrp2p") {
+                return None;
+            }
 
-        "JOI" => args.get(1).map(|email| Event::ParticipantInSwitchboard {
-            email: email.to_string(),
-        }),
+            Some(Event::RawMessage {
+                email: args.get(1)?.to_string(),
+                content_type: content_type.to_string(),
+                headers: mime_message.headers_except(&["MIME-Version", "Content-Type"]),
+                body: mime_message.body.as_bytes().to_vec(),
+            })
+        }
 
-        "IRO" => args.get(4).map(|email| Event::ParticipantInSwitchboard {
-            email: email.to_string(),
-        }),
+        "JOI" => participant_event(args.get(1..)?),
+        "IRO" => participant_event(args.get(4..)?),
 
-        "BYE" => args.get(1).map(|email| Event::ParticipantLeftSwitchboard {
-            email: email.to_string(),
+        "BYE" => args.get(1).map(|email| {
+            chunks.forget(email);
+            Event::ParticipantLeftSwitchboard {
+                email: email.to_string(),
+            }
         }),
 
         _ => None,
     }
 }
 
+/// Reads the email, display name and optional capabilities at the start of `args`.
+fn participant_event(args: &[&str]) -> Option<Event> {
+    let email = args.first()?;
+    let display_name = args
+        .get(1)
+        .and_then(|display_name| urlencoding::decode(display_name).ok())
+        .map(String::from)
+        .unwrap_or_else(|| email.to_string());
+
+    Some(Event::ParticipantInSwitchboard {
+        email: email.to_string(),
+        display_name,
+        capabilities: args
+            .get(2)
+            .and_then(|capabilities| capabilities.parse().ok())
+            .map(ClientCapabilities::from_bits_retain),
+    })
+}
+
+/// Returns whether the message is the last `IRO` of a roster.
+pub fn is_last_in_roster(message: &[u8]) -> bool {
+    let line_end = message
+        .windows(2)
+        .position(|window| window == b"\r\n")
+        .unwrap_or(message.len());
+
+    let Ok(line) = str::from_utf8(&message[..line_end]) else {
+        return false;
+    };
+
+    let args: Vec<&str> = line.split_ascii_whitespace().collect();
+
+    *args.first().unwrap_or(&"") == "IRO" && args.get(2).is_some() && args.get(2) == args.get(3)
+}
+
 pub fn into_internal_event(message: &[u8]) -> InternalEvent {
     let reply = unsafe { str::from_utf8_unchecked(message) }.to_string();
     let command = reply.lines().next().unwrap_or_default().to_string() + "\r\n";
@@ -0,0 +1,204 @@
+use crate::mime::MimeMessage;
+use std::collections::HashMap;
+
+/// Largest MSG payload the Switchboard accepts.
+pub(crate) const MAX_PAYLOAD_SIZE: usize = 1664;
+
+/// Most chunks a message is split into, or accepted from contacts.
+pub(crate) const MAX_CHUNKS: usize = 256;
+
+/// Most incomplete messages kept from each contact. Starting another one drops their oldest.
+const MAX_PENDING_PER_SENDER: usize = 8;
+
+/// Splits a MIME payload into chunks that fit in a MSG each. Payloads that already fit are returned as they are,
+/// otherwise the first chunk keeps the original headers and every chunk is tagged with the same `Message-ID`.
+/// Returns `None` if more than [MAX_CHUNKS] chunks would be needed.
+pub(crate) fn split(payload: &str) -> Option<Vec<String>> {
+    if payload.len() <= MAX_PAYLOAD_SIZE {
+        return Some(vec![payload.to_string()]);
+    }
+
+    let (headers, body) = payload.split_once("\r\n\r\n").unwrap_or((payload, ""));
+    let message_id = format!("{{{}}}", guid_create::GUID::rand());
+
+    // Leave room for the largest chunk number
+    let first_size = MAX_PAYLOAD_SIZE.saturating_sub(
+        format!("{headers}\r\nMessage-ID: {message_id}\r\nChunks: {MAX_CHUNKS}\r\n\r\n").len(),
+    );
+
+    let size = MAX_PAYLOAD_SIZE
+        .saturating_sub(format!("Message-ID: {message_id}\r\nChunk: {MAX_CHUNKS}\r\n\r\n").len());
+
+    let mut parts = Vec::new();
+    let mut rest = body;
+
+    while !rest.is_empty() {
+        let size = if parts.is_empty() { first_size } else { size };
+        let mut end = rest.floor_char_boundary(size);
+        if end == 0 {
+            end = rest.ceil_char_boundary(1);
+        }
+
+        let (part, remaining) = rest.split_at(end);
+        parts.push(part);
+        rest = remaining;
+
+        if parts.len() > MAX_CHUNKS {
+            return None;
+        }
+    }
+
+    let count = parts.len();
+    Some(
+        parts
+            .into_iter()
+            .enumerate()
+            .map(|(index, part)| {
+                if index == 0 {
+                    format!(
+                        "{headers}\r\nMessage-ID: {message_id}\r\nChunks: {count}\r\n\r\n{part}"
+                    )
+                } else {
+                    format!("Message-ID: {message_id}\r\nChunk: {index}\r\n\r\n{part}")
+                }
+            })
+            .collect(),
+    )
+}
+
+#[derive(Debug)]
+struct PendingMessage {
+    headers: String,
+    body: String,
+    chunks: usize,
+    received: usize,
+    started: u64,
+}
+
+/// Reassembles chunked messages received from a Switchboard.
+#[derive(Debug, Default)]
+pub(crate) struct MessageChunks {
+    pending: HashMap<(String, String), PendingMessage>,
+    started: u64,
+}
+
+impl MessageChunks {
+    /// Returns the complete payload once every chunk of a message was received. Payloads that aren't chunked are
+    /// returned as they are.
+    pub(crate) fn add(&mut self, email: &str, payload: String) -> Option<String> {
+        let message = MimeMessage::parse(&payload);
+        let Some(message_id) = message.header("Message-ID") else {
+            return Some(payload);
+        };
+
+        let key = (email.to_string(), message_id.to_string());
+        let body = message.body;
+        let other_headers = || message.headers_without(&["Message-ID", "Chunks"]);
+
+        if let Some(chunks) = message.header("Chunks") {
+            let chunks = chunks.parse::<usize>().ok()?;
+            if chunks <= 1 {
+                return Some(format!("{}\r\n\r\n{body}", other_headers()));
+            }
+
+            if chunks > MAX_CHUNKS {
+                return None;
+            }
+
+            self.pending.remove(&key);
+            self.drop_oldest(email);
+            self.started += 1;
+            self.pending.insert(
+                key,
+                PendingMessage {
+                    headers: other_headers(),
+                    body: body.to_string(),
+                    chunks,
+                    received: 1,
+                    started: self.started,
+                },
+            );
+
+            return None;
+        }
+
+        let chunk = message.header("Chunk")?.parse::<usize>().ok()?;
+        let pending = self.pending.get_mut(&key)?;
+
+        // Chunks arrive in order, a missing one means the message is lost
+        if chunk != pending.received {
+            self.pending.remove(&key);
+            return None;
+        }
+
+        pending.body.push_str(body);
+        pending.received += 1;
+
+        if pending.received < pending.chunks {
+            return None;
+        }
+
+        let pending = self.pending.remove(&key)?;
+        Some(format!("{}\r\n\r\n{}", pending.headers, pending.body))
+    }
+
+    /// Makes room for another incomplete message from a contact, dropping their oldest ones over the limit.
+    fn drop_oldest(&mut self, email: &str) {
+        loop {
+            let from_sender = self
+                .pending
+                .iter()
+                .filter(|((sender, _), _)| sender == email);
+            if from_sender.clone().count() < MAX_PENDING_PER_SENDER {
+                break;
+            }
+
+            let Some(oldest) = from_sender
+                .min_by_key(|(_, message)| message.started)
+                .map(|(key, _)| key.clone())
+            else {
+                break;
+            };
+
+            self.pending.remove(&oldest);
+        }
+    }
+
+    /// Drops the incomplete messages from a contact that left.
+    pub(crate) fn forget(&mut self, email: &str) {
+        self.pending.retain(|(sender, _), _| sender != email);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn first_chunk(id: usize) -> String {
+        format!("MIME-Version: 1.0\r\nMessage-ID: {{{id}}}\r\nChunks: 2\r\n\r\nHello ")
+    }
+
+    fn last_chunk(id: usize) -> String {
+        format!("Message-ID: {{{id}}}\r\nChunk: 1\r\n\r\nworld")
+    }
+
+    #[test]
+    fn pending_messages_are_capped() {
+        let mut chunks = MessageChunks::default();
+        for id in 0..=MAX_PENDING_PER_SENDER {
+            assert!(chunks.add("bob@passport.com", first_chunk(id)).is_none());
+        }
+
+        assert!(chunks.add("alice@passport.com", first_chunk(0)).is_none());
+        assert_eq!(chunks.pending.len(), MAX_PENDING_PER_SENDER + 1);
+
+        // The oldest message from Bob was dropped, not Alice's
+        assert!(chunks.add("bob@passport.com", last_chunk(0)).is_none());
+        assert_eq!(
+            chunks.add("bob@passport.com", last_chunk(1)).as_deref(),
+            Some("MIME-Version: 1.0\r\n\r\nHello world")
+        );
+
+        assert!(chunks.add("alice@passport.com", last_chunk(0)).is_some());
+    }
+}
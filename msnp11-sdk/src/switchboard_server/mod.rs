@@ -1,4 +1,8 @@
+mod ack_tracker;
 mod commands;
+pub(crate) mod event_fanout;
 mod event_matcher;
+mod message_chunks;
 mod p2p;
 pub mod switchboard;
+mod typing_tracker;
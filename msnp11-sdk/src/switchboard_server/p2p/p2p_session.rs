@@ -5,6 +5,8 @@ use crate::switchboard_server::commands::msg;
 use crate::switchboard_server::p2p::binary_header::BinaryHeader;
 #[cfg(feature = "file-transfers")]
 use crate::switchboard_server::p2p::file_context::FileContext;
+#[cfg(feature = "file-transfers")]
+use crate::transport::Connector;
 use base64::{Engine as _, engine::general_purpose::STANDARD};
 use core::str;
 use deku::{DekuContainerRead, DekuContainerWrite};
@@ -15,8 +17,6 @@ use std::sync::Arc;
 use std::sync::atomic::AtomicU32;
 #[cfg(feature = "file-transfers")]
 use tokio::io::{AsyncReadExt, AsyncWriteExt};
-#[cfg(feature = "file-transfers")]
-use tokio::net::TcpStream;
 use tokio::sync::mpsc::Sender;
 use tokio::sync::{RwLock, broadcast};
 
@@ -511,8 +511,10 @@ impl P2pSession {
     }
 
     #[cfg(feature = "file-transfers")]
+    #[allow(clippy::too_many_arguments)]
     pub async fn direct_connection_send_file(
         &mut self,
+        connector: &Connector,
         ips: &[String],
         port: u16,
         nonce: &guid_create::GUID,
@@ -521,7 +523,7 @@ impl P2pSession {
         file: &[u8],
     ) -> Result<(), P2pError> {
         for ip in ips {
-            if let Ok(mut socket) = TcpStream::connect((ip.as_str(), port)).await {
+            if let Ok(mut socket) = connector.connect_direct(ip, port).await {
                 let _ = socket.write_all(&u32::to_le_bytes(4)).await;
                 let _ = socket.write_all("foo\0".as_bytes()).await;
                 let _ = socket.write_all(&u32::to_le_bytes(48)).await;
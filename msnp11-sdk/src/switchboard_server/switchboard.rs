@@ -1,5 +1,8 @@
+use crate::enums::ack_mode::AckMode;
 use crate::enums::event::Event;
 use crate::enums::internal_event::InternalEvent;
+use crate::enums::protocol_direction::ProtocolDirection;
+use crate::enums::server_type::ServerType;
 use crate::errors::messaging_error::MessagingError;
 use crate::errors::p2p_error::P2pError;
 use crate::errors::sdk_error::SdkError;
@@ -7,13 +10,25 @@ use crate::errors::sdk_error::SdkError;
 use crate::event_handler::EventHandler;
 #[cfg(feature = "file-transfers")]
 use crate::models::file_transfer_request::FileTransferRequest;
+use crate::models::message_handle::MessageHandle;
+use crate::models::mime_header::MimeHeader;
+use crate::models::participant::Participant;
 use crate::models::plain_text::PlainText;
 use crate::models::user_data::UserData;
+use crate::protocol_log::ProtocolLogger;
+use crate::protocol_log::redact;
 use crate::receive_split::receive_split;
+use crate::switchboard_server::ack_tracker::AckTracker;
 use crate::switchboard_server::commands::{ans, cal, msg, usr};
-use crate::switchboard_server::event_matcher::{into_event, into_internal_event};
+use crate::switchboard_server::event_fanout::EventFanout;
+use crate::switchboard_server::event_matcher::{
+    into_event, into_internal_event, is_last_in_roster,
+};
+use crate::switchboard_server::message_chunks::MessageChunks;
 use crate::switchboard_server::p2p::binary_header::BinaryHeader;
 use crate::switchboard_server::p2p::p2p_session::P2pSession;
+use crate::switchboard_server::typing_tracker::{TYPING_INTERVAL, TypingTracker};
+use crate::transport::Connector;
 use core::str;
 use deku::DekuContainerRead;
 use log::{error, trace};
@@ -21,24 +36,32 @@ use std::error::Error;
 use std::io::Cursor;
 use std::sync::Arc;
 use std::sync::atomic::AtomicU32;
+use std::time::Duration;
 use tokio::io::AsyncWriteExt;
-use tokio::net::TcpStream;
-use tokio::sync::{RwLock, broadcast, mpsc};
+use tokio::sync::{Mutex, Notify, RwLock, broadcast, mpsc};
+use tokio::time::Instant;
 use tokio_util::sync::CancellationToken;
 
+/// How long an invited contact has to join before giving up on it.
+pub(crate) const INVITE_TIMEOUT: Duration = Duration::from_secs(30);
+
 /// Represents a messaging session with one or more contacts. The official MSN clients usually create a new session every time a conversation
 /// window is opened and leave it once it's closed.
 #[derive(Debug)]
 #[cfg_attr(feature = "uniffi", derive(uniffi::Object))]
 pub struct Switchboard {
-    event_tx: async_channel::Sender<Event>,
-    event_rx: async_channel::Receiver<Event>,
+    events: Arc<EventFanout>,
     sb_tx: mpsc::Sender<Vec<u8>>,
     internal_tx: broadcast::Sender<InternalEvent>,
     tr_id: Arc<AtomicU32>,
     session_id: RwLock<Option<String>>,
+    participants: Arc<RwLock<Vec<Participant>>>,
+    ack_tracker: Arc<Mutex<AckTracker>>,
+    last_typing_sent: Mutex<Option<Instant>>,
     cki_string: String,
     user_data: Arc<RwLock<UserData>>,
+    #[cfg_attr(not(feature = "file-transfers"), allow(dead_code))]
+    connector: Connector,
     cancellation_token: CancellationToken,
 }
 
@@ -48,61 +71,192 @@ impl Switchboard {
         port: &str,
         cki_string: &str,
         user_data: Arc<RwLock<UserData>>,
+        connector: &Connector,
+        protocol_logger: &ProtocolLogger,
     ) -> Result<Self, SdkError> {
-        let (event_tx, event_rx) = async_channel::unbounded();
+        let events = Arc::new(EventFanout::default());
         let (sb_tx, mut sb_rx) = mpsc::channel::<Vec<u8>>(256);
         let (internal_tx, _) = broadcast::channel::<InternalEvent>(256);
 
-        let socket = TcpStream::connect(format!("{server}:{port}"))
-            .await
+        let port = port
+            .parse::<u16>()
             .or(Err(SdkError::CouldNotConnectToServer))?;
 
-        let (mut rd, mut wr) = socket.into_split();
+        let (mut rd, mut wr) = connector
+            .connect(server, port, ServerType::Switchboard)
+            .await?;
         let task_internal_tx = internal_tx.clone();
-        let task_event_tx = event_tx.clone();
+        let task_events = events.clone();
+        let task_protocol_logger = protocol_logger.clone();
+        let participants = Arc::new(RwLock::new(Vec::<Participant>::new()));
+        let task_participants = participants.clone();
+        let ack_tracker = Arc::new(Mutex::new(AckTracker::default()));
+        let task_ack_tracker = ack_tracker.clone();
+        let typing_tracker = Arc::new(Mutex::new(TypingTracker::default()));
+        let task_typing_tracker = typing_tracker.clone();
+        let typing = Arc::new(Notify::new());
+        let task_typing = typing.clone();
 
         let cancellation_token = CancellationToken::new();
         let task_cancellation_token = cancellation_token.clone();
 
         tokio::spawn(async move {
+            let mut message_chunks = MessageChunks::default();
             'outer: while let Ok(messages) =
                 receive_split(&mut rd, task_cancellation_token.clone()).await
             {
                 for message in messages {
+                    task_protocol_logger.log(
+                        ProtocolDirection::Received,
+                        ServerType::Switchboard,
+                        &message,
+                    );
+
                     let internal_event = into_internal_event(&message);
                     if let Err(error) = task_internal_tx.send(internal_event) {
                         error!("{error}");
                     }
 
-                    let event = into_event(&message);
-                    if let Some(event) = event
-                        && let Err(error) = task_event_tx.send(event).await
-                    {
-                        error!("{error}");
-                        break 'outer;
+                    let mut event = into_event(&message, &mut message_chunks);
+                    if event.is_none() {
+                        event = task_ack_tracker.lock().await.handle_reply(&message);
+                    }
+
+                    let mut events = Vec::new();
+                    let mut roster = None;
+                    match &event {
+                        Some(Event::ParticipantInSwitchboard {
+                            email,
+                            display_name,
+                            capabilities,
+                        }) => {
+                            let participant = Participant {
+                                email: email.clone(),
+                                display_name: display_name.clone(),
+                                capabilities: *capabilities,
+                            };
+
+                            let mut participants = task_participants.write().await;
+                            match participants.iter_mut().find(|other| other.email == *email) {
+                                Some(other) => *other = participant,
+                                None => participants.push(participant),
+                            }
+
+                            if is_last_in_roster(&message) {
+                                roster = Some(Event::ParticipantRosterComplete {
+                                    participants: participants.clone(),
+                                });
+                            }
+                        }
+
+                        Some(Event::ParticipantLeftSwitchboard { email }) => {
+                            task_participants
+                                .write()
+                                .await
+                                .retain(|participant| participant.email != *email);
+
+                            if task_typing_tracker.lock().await.stopped(email) {
+                                events.push(Event::TypingStopped {
+                                    email: email.clone(),
+                                });
+                            }
+                        }
+
+                        Some(Event::TypingNotification { email }) => {
+                            if task_typing_tracker.lock().await.typed(email) {
+                                events.push(Event::TypingStarted {
+                                    email: email.clone(),
+                                });
+                            }
+
+                            task_typing.notify_one();
+                        }
+
+                        // A message ends typing right away
+                        Some(Event::TextMessage { email, .. })
+                            if task_typing_tracker.lock().await.stopped(email) =>
+                        {
+                            events.push(Event::TypingStopped {
+                                email: email.clone(),
+                            });
+                        }
+
+                        _ => (),
                     }
+
+                    events.extend(event);
+                    events.extend(roster);
+                    for event in events {
+                        if let Err(error) = task_events.send(event) {
+                            error!("{error}");
+                            break 'outer;
+                        }
+                    }
+                }
+            }
+
+            for event in task_ack_tracker.lock().await.fail_pending() {
+                if let Err(error) = task_events.send(event) {
+                    error!("{error}");
                 }
             }
 
-            if let Err(error) = task_event_tx.send(Event::Disconnected).await {
+            if let Err(error) = task_events.send(Event::Disconnected) {
                 error!("{error}");
             }
 
-            task_event_tx.close();
+            task_events.close();
             task_cancellation_token.cancel();
         });
 
-        let task_event_tx = event_tx.clone();
+        let task_events = events.clone();
+        let task_cancellation_token = cancellation_token.clone();
+
+        tokio::spawn(async move {
+            loop {
+                let deadline = typing_tracker.lock().await.next_deadline();
+                tokio::select! {
+                    _ = async {
+                        match deadline {
+                            Some(deadline) => tokio::time::sleep_until(deadline).await,
+                            None => std::future::pending().await,
+                        }
+                    } => {
+                        for email in typing_tracker.lock().await.expire() {
+                            if let Err(error) = task_events.send(Event::TypingStopped { email }) {
+                                error!("{error}");
+                            }
+                        }
+                    }
+
+                    _ = typing.notified() => (),
+                    _ = task_cancellation_token.cancelled() => break,
+                }
+            }
+        });
+
+        let task_events = events.clone();
         let task_cancellation_token = cancellation_token.clone();
+        let task_protocol_logger = protocol_logger.clone();
 
         tokio::spawn(async move {
             loop {
                 tokio::select! {
                     message = sb_rx.recv() => {
                         if let Some(message) = message {
+                            task_protocol_logger.log(
+                                ProtocolDirection::Sent,
+                                ServerType::Switchboard,
+                                &message,
+                            );
+
                             if let Err(error) = wr.write_all(&message).await {
                                 error!("{error}")
                             }
+
+                            if let Err(error) = wr.flush().await {
+                                error!("{error}")
+                            }
                         } else {
                             break;
                         }
@@ -114,23 +268,26 @@ impl Switchboard {
                 }
             }
 
-            if let Err(error) = task_event_tx.send(Event::Disconnected).await {
+            if let Err(error) = task_events.send(Event::Disconnected) {
                 error!("{error}");
             }
 
-            task_event_tx.close();
+            task_events.close();
             task_cancellation_token.cancel();
         });
 
         Ok(Self {
-            event_tx,
-            event_rx,
+            events,
             sb_tx,
             internal_tx,
             tr_id: Arc::new(AtomicU32::new(0)),
             session_id: RwLock::new(None),
+            participants,
+            ack_tracker,
+            last_typing_sent: Mutex::new(None),
             cki_string: cki_string.to_string(),
             user_data,
+            connector: connector.clone(),
             cancellation_token,
         })
     }
@@ -138,7 +295,7 @@ impl Switchboard {
     fn handle_p2p_events(&self) {
         let sb_tx = self.sb_tx.clone();
         #[cfg(feature = "file-transfers")]
-        let event_tx = self.event_tx.clone();
+        let events = self.events.clone();
         let mut internal_rx = self.internal_tx.subscribe();
         let mut command_internal_rx = self.internal_tx.subscribe();
         let task_cancellation_token = self.cancellation_token.clone();
@@ -190,7 +347,7 @@ impl Switchboard {
                                         let _ = msg::send_p2p(&tr_id, &sb_tx, &mut command_internal_rx, ack, &from).await;
                                     }
 
-                                    let _ = event_tx.send(Event::FileTransferRequest {
+                                    let _ = events.send(Event::FileTransferRequest {
                                         email: from.clone(),
                                         file_name,
                                         file_size,
@@ -201,7 +358,7 @@ impl Switchboard {
                                             call_id: call_id.to_string(),
                                             session_id,
                                         }
-                                    }).await.map_err(|error| error!("{error}"));
+                                    }).map_err(|error| error!("{error}"));
                                 }
 
                                 InternalEvent::P2pBye {
@@ -255,9 +412,9 @@ impl Switchboard {
         &self,
         email: &str,
         session_id: &str,
-    ) -> Result<(), Box<dyn Error + Send + Sync>> {
+    ) -> Result<Vec<String>, Box<dyn Error + Send + Sync>> {
         let mut internal_rx = self.internal_tx.subscribe();
-        ans::send(
+        let participants = ans::send(
             &self.tr_id,
             &self.sb_tx,
             &mut internal_rx,
@@ -271,7 +428,7 @@ impl Switchboard {
         let mut session_id_lock = self.session_id.write().await;
         *session_id_lock = Some(session_id.to_owned());
 
-        Ok(())
+        Ok(participants)
     }
 
     /// Adds a handler closure. If you're using this SDK with Rust, not through a foreign binding, then this is the preferred method of
@@ -281,7 +438,7 @@ impl Switchboard {
         F: Fn(Event) -> R + Send + 'static,
         R: Future<Output = ()> + Send,
     {
-        let event_rx = self.event_rx.clone();
+        let event_rx = self.events.subscribe();
         tokio::spawn(async move {
             while let Ok(event) = event_rx.recv().await {
                 f(event).await;
@@ -295,7 +452,7 @@ impl Switchboard {
     /// This exists for the foreign language bindings, with which generics don't
     /// work. Prefer [`add_event_handler_closure`][Switchboard::add_event_handler_closure] if using this SDK with Rust.
     pub fn add_event_handler(&self, handler: Arc<dyn EventHandler>) {
-        let event_rx = self.event_rx.clone();
+        let event_rx = self.events.subscribe();
         tokio::spawn(async move {
             while let Ok(event) = event_rx.recv().await {
                 handler.handle(event).await;
@@ -314,6 +471,78 @@ impl Switchboard {
         Ok(())
     }
 
+    /// Invites a contact and waits for it to join, so messages sent afterwards are delivered to it. Gives up after
+    /// [INVITE_TIMEOUT], once the session ends, or when the contact declines with a `BYE`.
+    pub(crate) async fn invite_and_wait(&self, email: &str) -> Result<(), SdkError> {
+        let mut internal_rx = self.internal_tx.subscribe();
+        let joined = async {
+            self.invite(email).await?;
+
+            loop {
+                if let InternalEvent::ServerReply(reply) =
+                    internal_rx.recv().await.or(Err(SdkError::ReceivingError))?
+                {
+                    let args: Vec<&str> = reply.split_ascii_whitespace().collect();
+                    match (args.first(), args.get(1)) {
+                        (Some(&"JOI"), Some(joined)) if joined.eq_ignore_ascii_case(email) => {
+                            return Ok(());
+                        }
+
+                        (Some(&"BYE"), None) => return Err(SdkError::Disconnected),
+                        (Some(&"BYE"), Some(left)) if left.eq_ignore_ascii_case(email) => {
+                            return Err(SdkError::InviteDeclined);
+                        }
+
+                        _ => (),
+                    }
+                }
+            }
+        };
+
+        tokio::select! {
+            result = tokio::time::timeout(INVITE_TIMEOUT, joined) => {
+                result.or(Err(SdkError::NoAnswer))?
+            }
+
+            _ = self.cancellation_token.cancelled() => Err(SdkError::Disconnected),
+        }
+    }
+
+    /// Returns the contacts currently in the session.
+    pub async fn get_participants(&self) -> Vec<String> {
+        self.participants
+            .read()
+            .await
+            .iter()
+            .map(|participant| participant.email.clone())
+            .collect()
+    }
+
+    /// Returns the contacts currently in the session, with their display names and capabilities.
+    pub async fn get_roster(&self) -> Vec<Participant> {
+        self.participants.read().await.clone()
+    }
+
+    /// Returns a contact in the session by email.
+    pub async fn get_participant(&self, email: &str) -> Option<Participant> {
+        self.participants
+            .read()
+            .await
+            .iter()
+            .find(|participant| participant.email == email)
+            .cloned()
+    }
+
+    /// Returns whether the connection to the Switchboard is still open.
+    pub fn is_connected(&self) -> bool {
+        !self.cancellation_token.is_cancelled()
+    }
+
+    /// Returns a receiver with its own copy of every event.
+    pub(crate) fn event_receiver(&self) -> async_channel::Receiver<Event> {
+        self.events.subscribe()
+    }
+
     /// Returns the session ID.
     pub async fn get_session_id(&self) -> Result<String, MessagingError> {
         let session_id = self.session_id.read().await;
@@ -324,21 +553,117 @@ impl Switchboard {
 
     /// Sends a plain text message to the session.
     pub async fn send_text_message(&self, message: &PlainText) -> Result<(), MessagingError> {
+        self.send_text_message_with_ack(message, AckMode::Acknowledged)
+            .await
+    }
+
+    /// Sends a plain text message to the session with the specified acknowledgement mode. Waits for the `ACK` if the
+    /// mode has one.
+    pub async fn send_text_message_with_ack(
+        &self,
+        message: &PlainText,
+        ack_mode: AckMode,
+    ) -> Result<(), MessagingError> {
+        // Typing again after this should be notified right away
+        *self.last_typing_sent.lock().await = None;
+
         let mut internal_rx = self.internal_tx.subscribe();
-        msg::send_text_message(&self.tr_id, &self.sb_tx, &mut internal_rx, message).await
+        msg::send_text_message(
+            &self.tr_id,
+            &self.sb_tx,
+            &mut internal_rx,
+            message,
+            ack_mode,
+        )
+        .await
+    }
+
+    /// Sends a plain text message to the session without waiting for its acknowledgement. The returned handle is
+    /// included in the [MessageDelivered][Event::MessageDelivered] or [MessageFailed][Event::MessageFailed] event
+    /// received later, depending on the mode.
+    pub async fn queue_text_message(
+        &self,
+        message: &PlainText,
+        ack_mode: AckMode,
+    ) -> Result<MessageHandle, MessagingError> {
+        *self.last_typing_sent.lock().await = None;
+        msg::queue_text_message(
+            &self.tr_id,
+            &self.sb_tx,
+            &self.ack_tracker,
+            message,
+            ack_mode,
+        )
+        .await
     }
 
     /// Sends a nudge to the session.
     pub async fn send_nudge(&self) -> Result<(), MessagingError> {
+        self.send_nudge_with_ack(AckMode::Acknowledged).await
+    }
+
+    /// Sends a nudge to the session with the specified acknowledgement mode. Waits for the `ACK` if the mode has one.
+    pub async fn send_nudge_with_ack(&self, ack_mode: AckMode) -> Result<(), MessagingError> {
         let mut internal_rx = self.internal_tx.subscribe();
-        msg::send_nudge(&self.tr_id, &self.sb_tx, &mut internal_rx).await
+        msg::send_nudge(&self.tr_id, &self.sb_tx, &mut internal_rx, ack_mode).await
+    }
+
+    /// Sends a nudge to the session without waiting for its acknowledgement, like
+    /// [queue_text_message][Switchboard::queue_text_message].
+    pub async fn queue_nudge(&self, ack_mode: AckMode) -> Result<MessageHandle, MessagingError> {
+        msg::queue_nudge(&self.tr_id, &self.sb_tx, &self.ack_tracker, ack_mode).await
     }
 
-    /// Sends an "is writing..." notification to the session.
+    /// Sends an "is writing..." notification to the session. Prefer [notify_typing][Switchboard::notify_typing], which
+    /// uses the user's email and limits how often these are sent.
     pub async fn send_typing_user(&self, email: &str) -> Result<(), MessagingError> {
         msg::send_typing_user(&self.tr_id, &self.sb_tx, email).await
     }
 
+    /// Tells the session the user is writing. Can be called on every keystroke, since an "is writing..." notification
+    /// is only sent every 5 seconds, like Messenger does.
+    pub async fn notify_typing(&self) -> Result<(), MessagingError> {
+        let mut last_typing_sent = self.last_typing_sent.lock().await;
+        if last_typing_sent.is_some_and(|sent| sent.elapsed() < TYPING_INTERVAL) {
+            return Ok(());
+        }
+
+        let user_email = self
+            .user_data
+            .read()
+            .await
+            .email
+            .clone()
+            .ok_or(MessagingError::NotLoggedIn)?;
+
+        msg::send_typing_user(&self.tr_id, &self.sb_tx, &user_email).await?;
+        *last_typing_sent = Some(Instant::now());
+        Ok(())
+    }
+
+    /// Sends a message with any content type, for extensions the SDK doesn't implement. `MIME-Version` and
+    /// `Content-Type` are added before the other headers, and the body must be UTF-8 text. With [Unacknowledged][AckMode::Unacknowledged] and
+    /// [Negative][AckMode::Negative] this returns once the message is sent.
+    pub async fn send_raw_message(
+        &self,
+        content_type: &str,
+        headers: &[MimeHeader],
+        body: &[u8],
+        ack_mode: AckMode,
+    ) -> Result<(), MessagingError> {
+        let mut internal_rx = self.internal_tx.subscribe();
+        msg::send_raw(
+            &self.tr_id,
+            &self.sb_tx,
+            &mut internal_rx,
+            content_type,
+            headers,
+            body,
+            ack_mode,
+        )
+        .await
+    }
+
     /// Requests a contact's display picture and handles the transfer process. A [DisplayPicture][Event::DisplayPicture] event
     /// is received once the transfer is complete.
     pub async fn request_contact_display_picture(
@@ -437,12 +762,11 @@ impl Switchboard {
         let bye = session.bye(email, &user_email)?;
         msg::send_p2p(&self.tr_id, &self.sb_tx, &mut internal_rx, bye, email).await?;
 
-        self.event_tx
+        self.events
             .send(Event::DisplayPicture {
                 email: email.to_owned(),
                 data: picture,
             })
-            .await
             .or(Err(P2pError::TransmittingError))?;
 
         Ok(())
@@ -525,6 +849,7 @@ impl Switchboard {
                         || bridge != "TCPv1"
                         || session
                             .direct_connection_send_file(
+                                &self.connector,
                                 &ips,
                                 port,
                                 &nonce,
@@ -731,14 +1056,14 @@ impl Switchboard {
     /// Disconnects from the Switchboard.
     pub async fn disconnect(&self) -> Result<(), SdkError> {
         let command = "OUT\r\n";
-        trace!("C: {command}");
+        trace!("C: {}", redact(command));
 
         self.sb_tx
             .send(command.as_bytes().to_vec())
             .await
             .or(Err(SdkError::TransmittingError))?;
 
-        self.event_tx.close();
+        self.events.close();
         self.cancellation_token.cancel();
         Ok(())
     }
@@ -0,0 +1,50 @@
+use std::collections::HashMap;
+use std::time::Duration;
+use tokio::time::Instant;
+
+/// How often Messenger repeats `TypingUser` while the user keeps typing.
+pub(crate) const TYPING_INTERVAL: Duration = Duration::from_secs(5);
+
+/// How long after the last `TypingUser` a contact is considered to have stopped typing.
+pub(crate) const TYPING_TIMEOUT: Duration = Duration::from_secs(6);
+
+/// Keeps which contacts are typing in a session and until when.
+#[derive(Debug, Default)]
+pub(crate) struct TypingTracker {
+    typing: HashMap<String, Instant>,
+}
+
+impl TypingTracker {
+    /// Records a `TypingUser` from a contact. Returns whether they just started typing.
+    pub(crate) fn typed(&mut self, email: &str) -> bool {
+        self.typing
+            .insert(email.to_string(), Instant::now() + TYPING_TIMEOUT)
+            .is_none()
+    }
+
+    /// Returns whether the contact was typing.
+    pub(crate) fn stopped(&mut self, email: &str) -> bool {
+        self.typing.remove(email).is_some()
+    }
+
+    pub(crate) fn next_deadline(&self) -> Option<Instant> {
+        self.typing.values().min().copied()
+    }
+
+    /// Removes and returns the contacts that stopped sending `TypingUser`.
+    pub(crate) fn expire(&mut self) -> Vec<String> {
+        let now = Instant::now();
+        let expired: Vec<String> = self
+            .typing
+            .iter()
+            .filter(|(_, deadline)| **deadline <= now)
+            .map(|(email, _)| email.clone())
+            .collect();
+
+        for email in &expired {
+            self.typing.remove(email);
+        }
+
+        expired
+    }
+}
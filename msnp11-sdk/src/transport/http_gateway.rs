@@ -0,0 +1,152 @@
+use crate::enums::server_type::ServerType;
+use crate::errors::sdk_error::SdkError;
+use crate::transport::{ReadHalf, WriteHalf};
+use log::{error, trace};
+use reqwest::Url;
+use reqwest::header::{CONTENT_TYPE, HeaderValue};
+use std::time::Duration;
+use tokio::io::{AsyncReadExt, AsyncWriteExt, DuplexStream};
+
+const POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+/// Keeps track of a session opened through `gateway.dll`. Commands written by the SDK are sent as
+/// POST bodies, and the server's replies come back in the response bodies, either to those
+/// requests or to regular polls.
+struct GatewaySession {
+    client: reqwest::Client,
+    url: Url,
+    server_type: ServerType,
+    ip: String,
+    session_id: Option<String>,
+    closed: bool,
+}
+
+impl GatewaySession {
+    async fn send(&mut self, body: Vec<u8>) -> Result<Vec<u8>, reqwest::Error> {
+        let mut url = self.url.clone();
+        if let Some(ref session_id) = self.session_id {
+            if body.is_empty() {
+                url.query_pairs_mut().append_pair("Action", "poll");
+            }
+
+            url.query_pairs_mut().append_pair("SessionID", session_id);
+        } else {
+            url.query_pairs_mut()
+                .append_pair("Action", "open")
+                .append_pair("Server", self.server_type.as_str())
+                .append_pair("IP", &self.ip);
+        }
+
+        trace!("Gateway request: {url}");
+        let response = self
+            .client
+            .post(url)
+            .header(
+                CONTENT_TYPE,
+                HeaderValue::from_static("application/x-msn-messenger"),
+            )
+            .body(body)
+            .send()
+            .await?
+            .error_for_status()?;
+
+        if let Some(messenger_header) = response.headers().get("X-MSN-Messenger")
+            && let Ok(messenger_header) = messenger_header.to_str()
+        {
+            for field in messenger_header.split(";") {
+                let Some((key, value)) = field.trim().split_once("=") else {
+                    continue;
+                };
+
+                match key {
+                    "SessionID" => self.session_id = Some(value.to_string()),
+                    "GW-IP" => {
+                        // Later requests go straight to the gateway that holds the session
+                        let _ = self
+                            .url
+                            .set_host(Some(value))
+                            .map_err(|error| error!("{error}"));
+                    }
+
+                    "Session" => self.closed = value == "close",
+                    _ => (),
+                }
+            }
+        }
+
+        Ok(response.bytes().await?.to_vec())
+    }
+
+    async fn run(mut self, stream: DuplexStream) {
+        let (mut rd, mut wr) = tokio::io::split(stream);
+        let mut buf = vec![0; 1664];
+
+        loop {
+            let body = tokio::select! {
+                received = rd.read(&mut buf) => {
+                    match received {
+                        Ok(received) if received > 0 => buf[..received].to_vec(),
+                        _ => break,
+                    }
+                }
+
+                _ = tokio::time::sleep(POLL_INTERVAL), if self.session_id.is_some() => Vec::new(),
+            };
+
+            match self.send(body).await {
+                Ok(reply) => {
+                    if wr.write_all(&reply).await.is_err() {
+                        break;
+                    }
+                }
+
+                Err(error) => {
+                    error!("{error}");
+                    return;
+                }
+            }
+
+            if self.closed {
+                return;
+            }
+        }
+
+        // There's no socket to close, so end the session explicitly
+        if self.session_id.is_some()
+            && let Err(error) = self.send(b"OUT\r\n".to_vec()).await
+        {
+            error!("{error}");
+        }
+    }
+}
+
+/// Returns halves that behave like a socket, backed by a task that polls the gateway.
+pub(super) fn connect(
+    gateway_url: &str,
+    server: &str,
+    port: u16,
+    server_type: ServerType,
+    client: reqwest::Client,
+) -> Result<(ReadHalf, WriteHalf), SdkError> {
+    let url = Url::parse(gateway_url).or(Err(SdkError::InvalidArgument))?;
+    let ip = if port == 1863 {
+        server.to_string()
+    } else {
+        format!("{server}:{port}")
+    };
+
+    let session = GatewaySession {
+        client,
+        url,
+        server_type,
+        ip,
+        session_id: None,
+        closed: false,
+    };
+
+    let (sdk_stream, gateway_stream) = tokio::io::duplex(65536);
+    tokio::spawn(session.run(gateway_stream));
+
+    let (rd, wr) = tokio::io::split(sdk_stream);
+    Ok((Box::new(rd), Box::new(wr)))
+}
@@ -0,0 +1,55 @@
+mod http_gateway;
+mod proxy;
+mod tcp;
+mod tls;
+
+use crate::enums::server_type::ServerType;
+use crate::enums::transport::Transport;
+use crate::errors::sdk_error::SdkError;
+use crate::models::proxy::Proxy;
+use tokio::io::{AsyncRead, AsyncWrite};
+
+pub(crate) type ReadHalf = Box<dyn AsyncRead + Send + Unpin>;
+pub(crate) type WriteHalf = Box<dyn AsyncWrite + Send + Unpin>;
+
+/// Opens connections using the client's transport and proxy settings.
+#[derive(Debug, Clone)]
+pub(crate) struct Connector {
+    transport: Transport,
+    proxy: Option<Proxy>,
+}
+
+impl Connector {
+    pub(crate) fn new(transport: Transport, proxy: Option<Proxy>) -> Self {
+        Self { transport, proxy }
+    }
+
+    pub(crate) async fn connect(
+        &self,
+        server: &str,
+        port: u16,
+        server_type: ServerType,
+    ) -> Result<(ReadHalf, WriteHalf), SdkError> {
+        match self.transport {
+            Transport::Tcp => tcp::connect(server, port, self.proxy.as_ref()).await,
+            Transport::Tls => tls::connect(server, port, self.proxy.as_ref()).await,
+            Transport::HttpGateway { ref gateway_url } => {
+                http_gateway::connect(gateway_url, server, port, server_type, self.http_client()?)
+            }
+        }
+    }
+
+    /// Opens a plain TCP connection regardless of the transport, used for direct P2P connections.
+    #[cfg(feature = "file-transfers")]
+    pub(crate) async fn connect_direct(
+        &self,
+        server: &str,
+        port: u16,
+    ) -> Result<tokio::net::TcpStream, SdkError> {
+        tcp::connect_stream(server, port, self.proxy.as_ref()).await
+    }
+
+    pub(crate) fn http_client(&self) -> Result<reqwest::Client, SdkError> {
+        proxy::http_client(self.proxy.as_ref())
+    }
+}
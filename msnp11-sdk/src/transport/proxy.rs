@@ -0,0 +1,149 @@
+use crate::enums::proxy_type::ProxyType;
+use crate::errors::sdk_error::SdkError;
+use crate::models::proxy::Proxy;
+use base64::{Engine as _, engine::general_purpose::STANDARD};
+use log::trace;
+use std::error::Error;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpStream;
+
+/// Asks the proxy to open a tunnel to the server. The server name is resolved by the proxy.
+pub(super) async fn handshake(
+    socket: &mut TcpStream,
+    proxy: &Proxy,
+    server: &str,
+    port: u16,
+) -> Result<(), SdkError> {
+    trace!(
+        "Connecting to {server}:{port} through {:?} proxy",
+        proxy.proxy_type
+    );
+    match proxy.proxy_type {
+        ProxyType::Socks5 => socks5_handshake(socket, proxy, server, port).await,
+        ProxyType::HttpConnect => http_connect_handshake(socket, proxy, server, port).await,
+    }
+    .or(Err(SdkError::ProxyError))
+}
+
+async fn socks5_handshake(
+    socket: &mut TcpStream,
+    proxy: &Proxy,
+    server: &str,
+    port: u16,
+) -> Result<(), Box<dyn Error + Send + Sync>> {
+    let credentials = proxy.username.as_ref().zip(proxy.password.as_ref());
+
+    // Version 5, offering no authentication and username/password if there are credentials
+    if credentials.is_some() {
+        socket.write_all(&[5, 2, 0, 2]).await?;
+    } else {
+        socket.write_all(&[5, 1, 0]).await?;
+    }
+
+    let mut reply = [0; 2];
+    socket.read_exact(&mut reply).await?;
+
+    match reply {
+        [5, 0] => (),
+        [5, 2] => {
+            let (username, password) = credentials.ok_or(SdkError::ProxyError)?;
+
+            let mut request = vec![1, username.len().try_into()?];
+            request.extend_from_slice(username.as_bytes());
+            request.push(password.len().try_into()?);
+            request.extend_from_slice(password.as_bytes());
+            socket.write_all(&request).await?;
+
+            let mut reply = [0; 2];
+            socket.read_exact(&mut reply).await?;
+
+            if reply[1] != 0 {
+                return Err(SdkError::ProxyError.into());
+            }
+        }
+
+        _ => return Err(SdkError::ProxyError.into()),
+    }
+
+    // CONNECT with a domain name address
+    let server_len: u8 = server.len().try_into()?;
+    let mut request = vec![5, 1, 0, 3, server_len];
+    request.extend_from_slice(server.as_bytes());
+    request.extend_from_slice(&port.to_be_bytes());
+    socket.write_all(&request).await?;
+
+    let mut reply = [0; 4];
+    socket.read_exact(&mut reply).await?;
+
+    if reply[1] != 0 {
+        return Err(SdkError::ProxyError.into());
+    }
+
+    // Skip the bound address and port
+    let address_len = match reply[3] {
+        1 => 4,
+        3 => socket.read_u8().await? as usize,
+        4 => 16,
+        _ => return Err(SdkError::ProxyError.into()),
+    };
+
+    let mut bound_address = vec![0; address_len + 2];
+    socket.read_exact(&mut bound_address).await?;
+    Ok(())
+}
+
+async fn http_connect_handshake(
+    socket: &mut TcpStream,
+    proxy: &Proxy,
+    server: &str,
+    port: u16,
+) -> Result<(), Box<dyn Error + Send + Sync>> {
+    let mut request = format!("CONNECT {server}:{port} HTTP/1.1\r\nHost: {server}:{port}\r\n");
+    if let Some(ref username) = proxy.username {
+        let password = proxy.password.as_deref().unwrap_or_default();
+        let credentials = STANDARD.encode(format!("{username}:{password}"));
+        request.push_str(format!("Proxy-Authorization: Basic {credentials}\r\n").as_str());
+    }
+
+    request.push_str("\r\n");
+    socket.write_all(request.as_bytes()).await?;
+
+    // Read byte by byte so nothing sent by the server after the headers is consumed
+    let mut reply = Vec::new();
+    while !reply.ends_with(b"\r\n\r\n") {
+        reply.push(socket.read_u8().await?);
+    }
+
+    let reply = String::from_utf8_lossy(&reply);
+    let status = reply.split_ascii_whitespace().nth(1).unwrap_or_default();
+
+    if status != "200" {
+        return Err(SdkError::ProxyError.into());
+    }
+
+    Ok(())
+}
+
+/// Creates an HTTP client that sends requests through the proxy, if there's one.
+pub(crate) fn http_client(proxy: Option<&Proxy>) -> Result<reqwest::Client, SdkError> {
+    let mut builder = reqwest::Client::builder();
+    if let Some(proxy) = proxy {
+        let scheme = match proxy.proxy_type {
+            ProxyType::Socks5 => "socks5h",
+            ProxyType::HttpConnect => "http",
+        };
+
+        let mut reqwest_proxy =
+            reqwest::Proxy::all(format!("{scheme}://{}:{}", proxy.server, proxy.port))
+                .or(Err(SdkError::ProxyError))?;
+
+        if let Some(ref username) = proxy.username {
+            reqwest_proxy =
+                reqwest_proxy.basic_auth(username, proxy.password.as_deref().unwrap_or_default());
+        }
+
+        builder = builder.proxy(reqwest_proxy);
+    }
+
+    builder.build().or(Err(SdkError::ProxyError))
+}
@@ -0,0 +1,50 @@
+use crate::errors::sdk_error::SdkError;
+use crate::models::proxy::Proxy;
+use crate::transport::proxy::handshake;
+use crate::transport::{ReadHalf, WriteHalf};
+use tokio::net::{TcpStream, lookup_host};
+
+async fn connect_resolved(server: &str, port: u16) -> Result<TcpStream, SdkError> {
+    let server_ips: Vec<_> = lookup_host((server, port))
+        .await
+        .or(Err(SdkError::ResolutionError))?
+        .collect();
+
+    // Prefer IPv4, as most servers only listen on it
+    let server_ip = server_ips
+        .iter()
+        .find(|ip| ip.is_ipv4())
+        .or(server_ips.first())
+        .ok_or(SdkError::ResolutionError)?;
+
+    TcpStream::connect(server_ip)
+        .await
+        .or(Err(SdkError::CouldNotConnectToServer))
+}
+
+pub(super) async fn connect_stream(
+    server: &str,
+    port: u16,
+    proxy: Option<&Proxy>,
+) -> Result<TcpStream, SdkError> {
+    let Some(proxy) = proxy else {
+        return connect_resolved(server, port).await;
+    };
+
+    let mut socket = connect_resolved(&proxy.server, proxy.port)
+        .await
+        .or(Err(SdkError::ProxyError))?;
+
+    handshake(&mut socket, proxy, server, port).await?;
+    Ok(socket)
+}
+
+pub(super) async fn connect(
+    server: &str,
+    port: u16,
+    proxy: Option<&Proxy>,
+) -> Result<(ReadHalf, WriteHalf), SdkError> {
+    let socket = connect_stream(server, port, proxy).await?;
+    let (rd, wr) = socket.into_split();
+    Ok((Box::new(rd), Box::new(wr)))
+}
@@ -0,0 +1,33 @@
+use crate::errors::sdk_error::SdkError;
+use crate::models::proxy::Proxy;
+use crate::transport::tcp::connect_stream;
+use crate::transport::{ReadHalf, WriteHalf};
+use std::sync::Arc;
+use tokio_rustls::TlsConnector;
+use tokio_rustls::rustls::pki_types::ServerName;
+use tokio_rustls::rustls::{ClientConfig, RootCertStore};
+
+pub(super) async fn connect(
+    server: &str,
+    port: u16,
+    proxy: Option<&Proxy>,
+) -> Result<(ReadHalf, WriteHalf), SdkError> {
+    let socket = connect_stream(server, port, proxy).await?;
+
+    let mut root_store = RootCertStore::empty();
+    root_store.extend(webpki_roots::TLS_SERVER_ROOTS.iter().cloned());
+
+    let config = ClientConfig::builder()
+        .with_root_certificates(root_store)
+        .with_no_client_auth();
+
+    let server_name = ServerName::try_from(server.to_owned()).or(Err(SdkError::ResolutionError))?;
+
+    let stream = TlsConnector::from(Arc::new(config))
+        .connect(server_name, socket)
+        .await
+        .or(Err(SdkError::CouldNotConnectToServer))?;
+
+    let (rd, wr) = tokio::io::split(stream);
+    Ok((Box::new(rd), Box::new(wr)))
+}
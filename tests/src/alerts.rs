@@ -0,0 +1,61 @@
+#[tokio::test]
+async fn alerts_and_system_messages() {
+    let events = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+    let client = msnp11_sdk::client::Client::new("127.0.0.1", 1863)
+        .await
+        .unwrap();
+
+    let result = client
+        .login(
+            "testing@example.com".to_string(),
+            "123456",
+            "http://localhost:3000/rdr/pprdr.asp",
+            "msnp11-sdk",
+            "0.6",
+        )
+        .await
+        .unwrap();
+
+    assert!(matches!(
+        result,
+        msnp11_sdk::enums::event::Event::Authenticated
+    ));
+
+    let handler_events = events.clone();
+    client.add_event_handler_closure(move |event| {
+        let events = handler_events.clone();
+        async move {
+            events.lock().unwrap().push(event);
+        }
+    });
+
+    // The mock server sends an alert and system messages after this
+    client
+        .set_blp(msnp11_sdk::PrivacyMode::BlockUnlisted)
+        .await
+        .unwrap();
+
+    tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+    client.disconnect().await.unwrap();
+
+    let events = events.lock().unwrap();
+    assert!(events.iter().any(|event| matches!(
+        event,
+        msnp11_sdk::enums::event::Event::Alert { id, site_url, action_url, text }
+            if id == "1342902633"
+                && site_url == "http://alerts.msn.com"
+                && action_url.as_deref() == Some("http://alerts.msn.com/a.htm")
+                && text == "Your favorite band is on tour"
+    )));
+
+    assert!(events.iter().any(|event| matches!(
+        event,
+        msnp11_sdk::enums::event::Event::ServerMaintenanceCancelled
+    )));
+
+    assert!(events.iter().any(|event| matches!(
+        event,
+        msnp11_sdk::enums::event::Event::SystemMessage { message_type: 5, args }
+            if *args == vec!["10".to_string(), "test".to_string()]
+    )));
+}
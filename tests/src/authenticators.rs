@@ -0,0 +1,124 @@
+async fn login_with(
+    cached_ticket: Option<msnp11_sdk::PassportTicket>,
+    authenticator: impl msnp11_sdk::Authenticator,
+) -> msnp11_sdk::PassportTicket {
+    let mut client = msnp11_sdk::client::Client::new("127.0.0.1", 1863)
+        .await
+        .unwrap();
+
+    let result: msnp11_sdk::enums::event::Event = match client
+        .login_with_cached_ticket(
+            "testing@example.com".to_string(),
+            cached_ticket.clone(),
+            &authenticator,
+            "msnp11-sdk",
+            "0.6",
+        )
+        .await
+    {
+        Ok(msnp11_sdk::enums::event::Event::RedirectedTo { server, port }) => {
+            client = msnp11_sdk::client::Client::new(&server, port)
+                .await
+                .unwrap();
+
+            client
+                .login_with_cached_ticket(
+                    "testing@example.com".to_string(),
+                    cached_ticket,
+                    &authenticator,
+                    "msnp11-sdk",
+                    "0.6",
+                )
+                .await
+                .unwrap()
+        }
+
+        Ok(msnp11_sdk::enums::event::Event::Authenticated) => {
+            msnp11_sdk::enums::event::Event::Authenticated
+        }
+        Err(err) => panic!("Login error: {err}"),
+        _ => msnp11_sdk::enums::event::Event::Disconnected,
+    };
+
+    assert!(matches!(
+        result,
+        msnp11_sdk::enums::event::Event::Authenticated
+    ));
+
+    let ticket = client.get_ticket().await.unwrap();
+    client.disconnect().await.unwrap();
+    ticket
+}
+
+#[tokio::test]
+async fn login_with_rst() {
+    let ticket = login_with(
+        None,
+        msnp11_sdk::RstAuthenticator::new(
+            "123456".to_string(),
+            "http://localhost:3000/RST.srf".to_string(),
+        ),
+    )
+    .await;
+
+    assert_eq!(ticket.ticket, "aaa123aaa123");
+    assert!(ticket.expires_at.is_some());
+    assert!(!ticket.is_expired());
+}
+
+#[tokio::test]
+async fn login_with_ticket() {
+    login_with(
+        None,
+        msnp11_sdk::TicketAuthenticator::new("aaa123aaa123".to_string()),
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn login_with_cached_ticket() {
+    // Passport would fail, so the cached ticket has to be used
+    let cached_ticket = msnp11_sdk::PassportTicket::new("aaa123aaa123".to_string(), None);
+    let ticket = login_with(
+        Some(cached_ticket.clone()),
+        msnp11_sdk::PassportAuthenticator::new(
+            "123456".to_string(),
+            "http://localhost:3000/invalid".to_string(),
+        ),
+    )
+    .await;
+
+    assert_eq!(ticket, cached_ticket);
+}
+
+#[tokio::test]
+async fn login_with_rejected_cached_ticket() {
+    let authenticator = msnp11_sdk::PassportAuthenticator::new(
+        "123456".to_string(),
+        "http://localhost:3000/rdr/pprdr.asp".to_string(),
+    );
+
+    let client = msnp11_sdk::client::Client::new("127.0.0.1", 1863)
+        .await
+        .unwrap();
+
+    let result = client
+        .login_with_cached_ticket(
+            "testing@example.com".to_string(),
+            Some(msnp11_sdk::PassportTicket::new(
+                "expired123".to_string(),
+                None,
+            )),
+            &authenticator,
+            "msnp11-sdk",
+            "0.6",
+        )
+        .await;
+
+    assert!(matches!(result, Err(msnp11_sdk::SdkError::TicketRejected)));
+    client.disconnect().await.unwrap();
+
+    // Logging in again on a new connection authenticates instead
+    let ticket = login_with(None, authenticator).await;
+    assert_eq!(ticket.ticket, "aaa123aaa123");
+}
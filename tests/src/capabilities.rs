@@ -0,0 +1,49 @@
+#[tokio::test]
+async fn capabilities() {
+    let client = msnp11_sdk::client::Client::new("127.0.0.1", 1863)
+        .await
+        .unwrap();
+
+    let result = client
+        .login(
+            "testing@example.com".to_string(),
+            "123456",
+            "http://localhost:3000/rdr/pprdr.asp",
+            "msnp11-sdk",
+            "0.6",
+        )
+        .await
+        .unwrap();
+
+    assert!(matches!(
+        result,
+        msnp11_sdk::enums::event::Event::Authenticated
+    ));
+
+    let capabilities = (msnp11_sdk::ClientCapabilities::MULTI_PACKET
+        | msnp11_sdk::ClientCapabilities::WINKS)
+        .with_msnc_version(3);
+
+    assert_eq!(capabilities.msnc_version(), 3);
+    assert!(capabilities.supports_p2p());
+
+    let capabilities = capabilities.with_msnc_version(4);
+    assert_eq!(capabilities.msnc_version(), 4);
+    assert_eq!(
+        capabilities,
+        msnp11_sdk::ClientCapabilities::from_bits_retain(0x40008020)
+    );
+    assert!(capabilities.contains(msnp11_sdk::ClientCapabilities::WINKS));
+
+    // Only answered by the mock server with the right client ID
+    client.set_capabilities(capabilities).await;
+    tokio::time::timeout(
+        std::time::Duration::from_secs(1),
+        client.set_presence(msnp11_sdk::MsnpStatus::Online),
+    )
+    .await
+    .unwrap()
+    .unwrap();
+
+    client.disconnect().await.unwrap();
+}
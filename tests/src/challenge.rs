@@ -0,0 +1,52 @@
+#[tokio::test]
+async fn challenge() {
+    let entries = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+    let client = msnp11_sdk::client::Client::new("127.0.0.1", 1863)
+        .await
+        .unwrap();
+
+    let handler_entries = entries.clone();
+    client.set_protocol_log_handler_closure(move |entry| {
+        handler_entries.lock().unwrap().push(entry);
+    });
+
+    let result = client
+        .login(
+            "testing@example.com".to_string(),
+            "123456",
+            "http://localhost:3000/rdr/pprdr.asp",
+            "msnp11-sdk",
+            "0.6",
+        )
+        .await
+        .unwrap();
+
+    assert!(matches!(
+        result,
+        msnp11_sdk::enums::event::Event::Authenticated
+    ));
+
+    // The mock server sends a challenge after this and only replies to a correct QRY
+    client
+        .set_presence(msnp11_sdk::enums::msnp_status::MsnpStatus::Busy)
+        .await
+        .unwrap();
+
+    let accepted = msnp11_sdk::ProtocolLogEntry {
+        direction: msnp11_sdk::ProtocolDirection::Received,
+        server_type: msnp11_sdk::ServerType::NotificationServer,
+        command: "QRY 8".to_string(),
+        payload_length: 0,
+    };
+
+    for _ in 0..20 {
+        if entries.lock().unwrap().contains(&accepted) {
+            break;
+        }
+
+        tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+    }
+
+    assert!(entries.lock().unwrap().contains(&accepted));
+    client.disconnect().await.unwrap();
+}
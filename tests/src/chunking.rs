@@ -0,0 +1,59 @@
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+#[tokio::test]
+async fn long_messages_are_chunked() {
+    let client = msnp11_sdk::client::Client::new("127.0.0.1", 1863)
+        .await
+        .unwrap();
+
+    let result = client
+        .login(
+            "testing@example.com".to_string(),
+            "123456",
+            "http://localhost:3000/rdr/pprdr.asp",
+            "msnp11-sdk",
+            "0.6",
+        )
+        .await
+        .unwrap();
+
+    assert!(matches!(
+        result,
+        msnp11_sdk::enums::event::Event::Authenticated
+    ));
+
+    let switchboard = client.create_session("bob@passport.com").await.unwrap();
+
+    let messages = Arc::new(Mutex::new(Vec::new()));
+    let handler_messages = messages.clone();
+    switchboard.add_event_handler_closure(move |event| {
+        let messages = handler_messages.clone();
+        async move {
+            if let msnp11_sdk::enums::event::Event::TextMessage { email, message } = event {
+                assert_eq!(email, "bob@passport.com");
+                messages.lock().unwrap().push(message.text);
+            }
+        }
+    });
+
+    // Sent in two chunks
+    let message = msnp11_sdk::PlainText {
+        bold: false,
+        italic: false,
+        underline: false,
+        strikethrough: false,
+        color: "ff".to_string(),
+        text: "a".repeat(2000),
+        ..Default::default()
+    };
+
+    switchboard.send_text_message(&message).await.unwrap();
+    tokio::time::sleep(Duration::from_millis(100)).await;
+
+    // Bob's chunked reply is received as a single message
+    assert_eq!(*messages.lock().unwrap(), vec!["Hello world"]);
+
+    switchboard.disconnect().await.unwrap();
+    client.disconnect().await.unwrap();
+}
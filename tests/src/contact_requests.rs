@@ -0,0 +1,86 @@
+#[tokio::test]
+async fn contact_requests() {
+    let client = msnp11_sdk::client::Client::new("127.0.0.1", 1863)
+        .await
+        .unwrap();
+
+    let commands = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+    let handler_commands = commands.clone();
+    client.set_protocol_log_handler_closure(move |entry| {
+        if entry.direction == msnp11_sdk::ProtocolDirection::Sent {
+            handler_commands.lock().unwrap().push(entry.command);
+        }
+    });
+
+    let result = client
+        .login(
+            "testing@example.com".to_string(),
+            "123456",
+            "http://localhost:3000/rdr/pprdr.asp",
+            "msnp11-sdk",
+            "0.6",
+        )
+        .await
+        .unwrap();
+
+    assert!(matches!(
+        result,
+        msnp11_sdk::enums::event::Event::Authenticated
+    ));
+
+    assert!(client.get_contact_requests().await.is_empty());
+
+    // The mock server sends two requests after changing the presence
+    client
+        .set_presence(msnp11_sdk::MsnpStatus::AppearOffline)
+        .await
+        .unwrap();
+
+    tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+    assert_eq!(
+        client.get_contact_requests().await,
+        vec![
+            msnp11_sdk::ContactRequest {
+                email: "carol@passport.com".to_string(),
+                display_name: "Carol".to_string(),
+            },
+            msnp11_sdk::ContactRequest {
+                email: "dave@passport.com".to_string(),
+                display_name: "Dave".to_string(),
+            },
+        ]
+    );
+
+    assert!(matches!(
+        client
+            .reject_contact_request("fred@passport.com", false)
+            .await,
+        Err(msnp11_sdk::ContactError::NoPendingRequest)
+    ));
+
+    client
+        .accept_contact_request(
+            "carol@passport.com",
+            true,
+            Some("124153dc-a695-4f6c-93e8-8e07c9775251"),
+        )
+        .await
+        .unwrap();
+
+    assert!(client.is_contact_allowed("carol@passport.com").await);
+
+    client
+        .reject_contact_request("dave@passport.com", true)
+        .await
+        .unwrap();
+
+    assert!(!client.is_contact_allowed("dave@passport.com").await);
+    assert!(client.get_contact_requests().await.is_empty());
+
+    // Requests received live are in the pending list too
+    let commands = commands.lock().unwrap().clone();
+    assert!(commands.contains(&"REM 11 PL carol@passport.com".to_string()));
+    assert!(commands.contains(&"REM 13 PL dave@passport.com".to_string()));
+
+    client.disconnect().await.unwrap();
+}
@@ -0,0 +1,157 @@
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+async fn login() -> msnp11_sdk::Client {
+    let client = msnp11_sdk::client::Client::new("127.0.0.1", 1863)
+        .await
+        .unwrap();
+
+    let result = client
+        .login(
+            "testing@example.com".to_string(),
+            "123456",
+            "http://localhost:3000/rdr/pprdr.asp",
+            "msnp11-sdk",
+            "0.6",
+        )
+        .await
+        .unwrap();
+
+    assert!(matches!(
+        result,
+        msnp11_sdk::enums::event::Event::Authenticated
+    ));
+
+    client
+}
+
+fn message() -> msnp11_sdk::PlainText {
+    msnp11_sdk::PlainText {
+        bold: false,
+        italic: false,
+        underline: false,
+        strikethrough: false,
+        color: "ff".to_string(),
+        text: "h".to_string(),
+        ..Default::default()
+    }
+}
+
+#[tokio::test]
+async fn conversation_outlives_sessions() {
+    let client = login().await;
+    client.enable_conversations();
+
+    let conversation = client.get_conversation(&["Bob@passport.com"]).await;
+    assert_eq!(conversation.get_participants(), vec!["bob@passport.com"]);
+    assert!(conversation.get_switchboard().await.is_none());
+
+    let messages = Arc::new(Mutex::new(0));
+    let handler_messages = messages.clone();
+    conversation.add_event_handler_closure(move |event| {
+        let messages = handler_messages.clone();
+        async move {
+            if let msnp11_sdk::enums::event::Event::TextMessage { email, message } = event {
+                assert_eq!(email, "bob@passport.com");
+                assert_eq!(message.text, "h");
+                *messages.lock().unwrap() += 1;
+            }
+        }
+    });
+
+    // Every handler gets its own copy of the events
+    let copies = Arc::new(Mutex::new(0));
+    let handler_copies = copies.clone();
+    conversation.add_event_handler_closure(move |event| {
+        let copies = handler_copies.clone();
+        async move {
+            if let msnp11_sdk::enums::event::Event::TextMessage { .. } = event {
+                *copies.lock().unwrap() += 1;
+            }
+        }
+    });
+
+    // Opens a session, which Bob leaves after replying
+    conversation.send_text_message(&message()).await.unwrap();
+    tokio::time::sleep(Duration::from_millis(100)).await;
+
+    let switchboard = conversation.get_switchboard().await.unwrap();
+    assert!(switchboard.get_participants().await.is_empty());
+
+    // Invites Bob back to the same session
+    conversation.send_text_message(&message()).await.unwrap();
+    assert!(Arc::ptr_eq(
+        &switchboard,
+        &conversation.get_switchboard().await.unwrap()
+    ));
+
+    // Opens a new session once the previous one is closed
+    switchboard.disconnect().await.unwrap();
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    assert!(conversation.get_switchboard().await.is_none());
+
+    conversation.send_text_message(&message()).await.unwrap();
+    tokio::time::sleep(Duration::from_millis(100)).await;
+
+    assert!(!Arc::ptr_eq(
+        &switchboard,
+        &conversation.get_switchboard().await.unwrap()
+    ));
+
+    assert_eq!(*messages.lock().unwrap(), 2);
+    assert_eq!(*copies.lock().unwrap(), 2);
+    assert!(Arc::ptr_eq(
+        &conversation,
+        &client.get_conversation(&["bob@passport.com"]).await
+    ));
+
+    assert_eq!(client.get_conversations().await.len(), 1);
+    conversation
+        .get_switchboard()
+        .await
+        .unwrap()
+        .disconnect()
+        .await
+        .unwrap();
+    client.disconnect().await.unwrap();
+}
+
+#[tokio::test]
+async fn invitation_starts_conversation() {
+    let client = login().await;
+    client.enable_conversations();
+
+    let started = Arc::new(Mutex::new(Vec::new()));
+    let handler_started = started.clone();
+    client.add_event_handler_closure(move |event| {
+        let started = handler_started.clone();
+        async move {
+            match event {
+                msnp11_sdk::enums::event::Event::ConversationStarted(conversation) => {
+                    started.lock().unwrap().push(conversation);
+                }
+
+                msnp11_sdk::enums::event::Event::SessionAnswered(_) => {
+                    panic!("Answered session wasn't sent to a conversation")
+                }
+
+                _ => (),
+            }
+        }
+    });
+
+    // GTC abuse from the mock server
+    client
+        .set_gtc(msnp11_sdk::AddNotificationMode::DontPrompt)
+        .await
+        .unwrap();
+
+    tokio::time::sleep(Duration::from_millis(200)).await;
+
+    let started = started.lock().unwrap().clone();
+    assert_eq!(started.len(), 1);
+    assert_eq!(started[0].get_participants(), vec!["bob@passport.com"]);
+    assert_eq!(client.get_conversations().await.len(), 1);
+
+    client.disconnect().await.unwrap();
+}
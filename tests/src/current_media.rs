@@ -0,0 +1,88 @@
+use std::sync::{Arc, Mutex};
+
+#[tokio::test]
+async fn current_media() {
+    let current_media = msnp11_sdk::CurrentMedia::decode(
+        "\\0Music\\01\\0{0} - {1}\\0Rock & Roll\\0Band\\0Album\\0\\0",
+    )
+    .unwrap();
+
+    assert_eq!(current_media.app, "");
+    assert_eq!(
+        current_media.media_type,
+        msnp11_sdk::CurrentMediaType::Music
+    );
+    assert!(current_media.enabled);
+    assert_eq!(
+        current_media.fields,
+        vec!["Rock & Roll", "Band", "Album", ""]
+    );
+    assert_eq!(current_media.to_display_string(), "Rock & Roll - Band");
+    assert_eq!(
+        current_media.encode(),
+        "\\0Music\\01\\0{0} - {1}\\0Rock & Roll\\0Band\\0Album\\0\\0"
+    );
+
+    assert_eq!(msnp11_sdk::CurrentMedia::decode(""), None);
+
+    let client = msnp11_sdk::client::Client::new("127.0.0.1", 1863)
+        .await
+        .unwrap();
+
+    let result = client
+        .login(
+            "testing@example.com".to_string(),
+            "123456",
+            "http://localhost:3000/rdr/pprdr.asp",
+            "msnp11-sdk",
+            "0.6",
+        )
+        .await
+        .unwrap();
+
+    assert!(matches!(
+        result,
+        msnp11_sdk::enums::event::Event::Authenticated
+    ));
+
+    let songs = Arc::new(Mutex::new(Vec::new()));
+    let handler_songs = songs.clone();
+    client.add_event_handler_closure(move |event| {
+        let songs = handler_songs.clone();
+        async move {
+            if let msnp11_sdk::enums::event::Event::PersonalMessageUpdate {
+                personal_message, ..
+            } = event
+                && let Some(current_media) = personal_message.current_media
+            {
+                songs
+                    .lock()
+                    .unwrap()
+                    .push(current_media.to_display_string());
+            }
+        }
+    });
+
+    client
+        .set_presence(msnp11_sdk::MsnpStatus::Online)
+        .await
+        .unwrap();
+
+    // Only answered by the mock server if escaped and encoded correctly
+    client
+        .set_personal_message(&msnp11_sdk::PersonalMessage {
+            psm: "listening".to_string(),
+            current_media: Some(msnp11_sdk::CurrentMedia {
+                app: "".to_string(),
+                media_type: msnp11_sdk::CurrentMediaType::Music,
+                enabled: true,
+                format: "{0} - {1}".to_string(),
+                fields: vec!["Rock & Roll".to_string(), "Band".to_string()],
+            }),
+        })
+        .await
+        .unwrap();
+
+    assert_eq!(*songs.lock().unwrap(), vec!["Rock & Roll - Band"]);
+    client.disconnect().await.unwrap();
+}
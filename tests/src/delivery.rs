@@ -0,0 +1,86 @@
+use msnp11_sdk::{AckMode, MessageHandle};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+#[derive(Debug, PartialEq)]
+enum Delivery {
+    Delivered(MessageHandle),
+    Failed(MessageHandle),
+}
+
+#[tokio::test]
+async fn delivery_receipts() {
+    let client = msnp11_sdk::client::Client::new("127.0.0.1", 1863)
+        .await
+        .unwrap();
+
+    let result = client
+        .login(
+            "testing@example.com".to_string(),
+            "123456",
+            "http://localhost:3000/rdr/pprdr.asp",
+            "msnp11-sdk",
+            "0.6",
+        )
+        .await
+        .unwrap();
+
+    assert!(matches!(
+        result,
+        msnp11_sdk::enums::event::Event::Authenticated
+    ));
+
+    let switchboard = client.create_session("bob@passport.com").await.unwrap();
+
+    let deliveries = Arc::new(Mutex::new(Vec::new()));
+    let handler_deliveries = deliveries.clone();
+    switchboard.add_event_handler_closure(move |event| {
+        let deliveries = handler_deliveries.clone();
+        async move {
+            match event {
+                msnp11_sdk::enums::event::Event::MessageDelivered { handle } => {
+                    deliveries.lock().unwrap().push(Delivery::Delivered(handle));
+                }
+
+                msnp11_sdk::enums::event::Event::MessageFailed { handle } => {
+                    deliveries.lock().unwrap().push(Delivery::Failed(handle));
+                }
+
+                _ => (),
+            }
+        }
+    });
+
+    let message = msnp11_sdk::PlainText {
+        color: "ff".to_string(),
+        text: "h".to_string(),
+        ..Default::default()
+    };
+
+    // ACK from the mock server
+    let delivered = switchboard
+        .queue_text_message(&message, AckMode::Acknowledged)
+        .await
+        .unwrap();
+
+    // NAK from the mock server
+    let failed = switchboard.queue_nudge(AckMode::Negative).await.unwrap();
+
+    // No reply expected
+    switchboard
+        .send_text_message_with_ack(&message, AckMode::Unacknowledged)
+        .await
+        .unwrap();
+
+    tokio::time::sleep(Duration::from_millis(100)).await;
+
+    assert_eq!(delivered, MessageHandle { id: 3 });
+    assert_eq!(failed, MessageHandle { id: 4 });
+    assert_eq!(
+        *deliveries.lock().unwrap(),
+        vec![Delivery::Delivered(delivered), Delivery::Failed(failed),]
+    );
+
+    switchboard.disconnect().await.unwrap();
+    client.disconnect().await.unwrap();
+}
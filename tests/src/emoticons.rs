@@ -0,0 +1,144 @@
+use msnp11_sdk::models::emoticons::tokenize_emoticons;
+use msnp11_sdk::{CustomEmoticon, TextToken};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+fn message(text: &str) -> msnp11_sdk::PlainText {
+    msnp11_sdk::PlainText {
+        text: text.to_string(),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn standard_emoticons() {
+    let tokens = tokenize_emoticons(&message("Hi :-) (y) ok(brb)é:P"), Vec::new());
+    assert_eq!(
+        tokens,
+        vec![
+            TextToken::Text("Hi ".to_string()),
+            TextToken::Emoticon {
+                shortcut: ":-)".to_string(),
+                name: "smile".to_string(),
+            },
+            TextToken::Text(" ".to_string()),
+            TextToken::Emoticon {
+                shortcut: "(y)".to_string(),
+                name: "thumbs_up".to_string(),
+            },
+            TextToken::Text(" ok".to_string()),
+            TextToken::Emoticon {
+                shortcut: "(brb)".to_string(),
+                name: "be_right_back".to_string(),
+            },
+            TextToken::Text("é".to_string()),
+            TextToken::Emoticon {
+                shortcut: ":P".to_string(),
+                name: "tongue_out".to_string(),
+            },
+        ]
+    );
+
+    let tokens = tokenize_emoticons(&message("(?)(CI)"), Vec::new());
+    assert_eq!(
+        tokens,
+        vec![
+            TextToken::Emoticon {
+                shortcut: "(?)".to_string(),
+                name: "asl".to_string(),
+            },
+            TextToken::Emoticon {
+                shortcut: "(CI)".to_string(),
+                name: "cigarette".to_string(),
+            },
+        ]
+    );
+}
+
+#[test]
+fn custom_emoticons_override() {
+    let custom = vec![CustomEmoticon {
+        shortcut: ":)".to_string(),
+        msn_object: "<msnobj/>".to_string(),
+    }];
+
+    let tokens = tokenize_emoticons(&message(":):-)"), custom);
+    assert_eq!(
+        tokens,
+        vec![
+            TextToken::CustomEmoticon {
+                shortcut: ":)".to_string(),
+                msn_object: "<msnobj/>".to_string(),
+            },
+            TextToken::Emoticon {
+                shortcut: ":-)".to_string(),
+                name: "smile".to_string(),
+            },
+        ]
+    );
+}
+
+#[tokio::test]
+async fn custom_emoticons_received() {
+    let client = msnp11_sdk::client::Client::new("127.0.0.1", 1863)
+        .await
+        .unwrap();
+
+    let result = client
+        .login(
+            "testing@example.com".to_string(),
+            "123456",
+            "http://localhost:3000/rdr/pprdr.asp",
+            "msnp11-sdk",
+            "0.6",
+        )
+        .await
+        .unwrap();
+
+    assert!(matches!(
+        result,
+        msnp11_sdk::enums::event::Event::Authenticated
+    ));
+
+    let emoticons = Arc::new(Mutex::new(Vec::new()));
+    let handler_emoticons = emoticons.clone();
+    client.add_event_handler_closure(move |event| {
+        let emoticons = handler_emoticons.clone();
+        async move {
+            if let msnp11_sdk::enums::event::Event::SessionAnswered(switchboard) = event {
+                switchboard.add_event_handler_closure(move |event| {
+                    let emoticons = emoticons.clone();
+                    async move {
+                        if let msnp11_sdk::enums::event::Event::CustomEmoticons {
+                            email,
+                            emoticons: declared,
+                        } = event
+                        {
+                            assert_eq!(email, "bob@passport.com");
+                            emoticons.lock().unwrap().extend(declared);
+                        }
+                    }
+                });
+            }
+        }
+    });
+
+    // GTC abuse from the mock server
+    client
+        .set_gtc(msnp11_sdk::AddNotificationMode::DontPrompt)
+        .await
+        .unwrap();
+
+    tokio::time::sleep(Duration::from_millis(200)).await;
+
+    let emoticons = emoticons.lock().unwrap().clone();
+    assert_eq!(emoticons.len(), 1);
+    assert_eq!(emoticons[0].shortcut, "(bob)");
+    assert!(
+        emoticons[0]
+            .msn_object
+            .starts_with("<msnobj Creator=\"bob@passport.com\"")
+    );
+
+    client.disconnect().await.unwrap();
+}
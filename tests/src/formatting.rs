@@ -0,0 +1,73 @@
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+#[tokio::test]
+async fn formatting_round_trip() {
+    let client = msnp11_sdk::client::Client::new("127.0.0.1", 1863)
+        .await
+        .unwrap();
+
+    let result = client
+        .login(
+            "testing@example.com".to_string(),
+            "123456",
+            "http://localhost:3000/rdr/pprdr.asp",
+            "msnp11-sdk",
+            "0.6",
+        )
+        .await
+        .unwrap();
+
+    assert!(matches!(
+        result,
+        msnp11_sdk::enums::event::Event::Authenticated
+    ));
+
+    let switchboard = client.create_session("bob@passport.com").await.unwrap();
+
+    let messages = Arc::new(Mutex::new(Vec::new()));
+    let handler_messages = messages.clone();
+    switchboard.add_event_handler_closure(move |event| {
+        let messages = handler_messages.clone();
+        async move {
+            if let msnp11_sdk::enums::event::Event::TextMessage { message, .. } = event {
+                messages.lock().unwrap().push(message);
+            }
+        }
+    });
+
+    let message = msnp11_sdk::PlainText {
+        bold: false,
+        italic: true,
+        underline: false,
+        strikethrough: false,
+        color: "ff".to_string(),
+        font_name: "Segoe UI".to_string(),
+        charset: 0,
+        pitch_family: 0x22,
+        right_to_left: true,
+        text: "Hello\nworld".to_string(),
+    };
+
+    // Bob echoes the message and sends one from Messenger 7.5
+    switchboard.send_text_message(&message).await.unwrap();
+    tokio::time::sleep(Duration::from_millis(100)).await;
+
+    let messages = messages.lock().unwrap().clone();
+    assert_eq!(messages.len(), 2);
+    assert_eq!(messages[0], message);
+
+    // Headers in a different order
+    let messenger = &messages[1];
+    assert_eq!(messenger.font_name, "MS Shell Dlg 2");
+    assert!(messenger.bold);
+    assert!(!messenger.italic);
+    assert_eq!(messenger.color, "808080");
+    assert_eq!(messenger.charset, 0x80);
+    assert_eq!(messenger.pitch_family, 0x31);
+    assert!(!messenger.right_to_left);
+    assert_eq!(messenger.text, "こんにちは");
+
+    switchboard.disconnect().await.unwrap();
+    client.disconnect().await.unwrap();
+}
@@ -0,0 +1,165 @@
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+async fn login() -> msnp11_sdk::Client {
+    let client = msnp11_sdk::client::Client::new("127.0.0.1", 1863)
+        .await
+        .unwrap();
+
+    let result = client
+        .login(
+            "testing@example.com".to_string(),
+            "123456",
+            "http://localhost:3000/rdr/pprdr.asp",
+            "msnp11-sdk",
+            "0.6",
+        )
+        .await
+        .unwrap();
+
+    assert!(matches!(
+        result,
+        msnp11_sdk::enums::event::Event::Authenticated
+    ));
+
+    client
+}
+
+#[tokio::test]
+async fn invite_several_contacts() {
+    let client = login().await;
+    let group_chat = client.create_group_chat().await.unwrap();
+
+    let results = group_chat
+        .invite(&[
+            "alice@passport.com",
+            "carol@passport.com",
+            "erin@passport.com",
+            "dave",
+        ])
+        .await;
+
+    assert_eq!(
+        results,
+        vec![
+            msnp11_sdk::InviteResult {
+                email: "alice@passport.com".to_string(),
+                status: msnp11_sdk::InviteStatus::Joined,
+            },
+            msnp11_sdk::InviteResult {
+                email: "carol@passport.com".to_string(),
+                status: msnp11_sdk::InviteStatus::Offline,
+            },
+            msnp11_sdk::InviteResult {
+                email: "erin@passport.com".to_string(),
+                status: msnp11_sdk::InviteStatus::Joined,
+            },
+            msnp11_sdk::InviteResult {
+                email: "dave".to_string(),
+                status: msnp11_sdk::InviteStatus::InvalidContact,
+            },
+        ]
+    );
+
+    let results = group_chat.invite(&["Alice@passport.com"]).await;
+    assert_eq!(
+        results[0].status,
+        msnp11_sdk::InviteStatus::AlreadyInSession
+    );
+
+    let mut members = group_chat.get_members().await;
+    members.sort();
+    assert_eq!(members, vec!["alice@passport.com", "erin@passport.com"]);
+    assert!(group_chat.is_group().await);
+
+    let one_to_one = Arc::new(Mutex::new(Vec::new()));
+    let handler_one_to_one = one_to_one.clone();
+    group_chat.add_event_handler_closure(move |event| {
+        let one_to_one = handler_one_to_one.clone();
+        async move {
+            if let msnp11_sdk::enums::event::Event::GroupChatBecameOneToOne { email } = event {
+                one_to_one.lock().unwrap().push(email);
+            }
+        }
+    });
+
+    // Every handler gets its own copy of the events
+    let copies = Arc::new(Mutex::new(0));
+    let handler_copies = copies.clone();
+    group_chat.add_event_handler_closure(move |event| {
+        let copies = handler_copies.clone();
+        async move {
+            if let msnp11_sdk::enums::event::Event::GroupChatBecameOneToOne { .. } = event {
+                *copies.lock().unwrap() += 1;
+            }
+        }
+    });
+
+    // Handlers on the session get the same events as the group chat
+    let left = Arc::new(Mutex::new(Vec::new()));
+    let handler_left = left.clone();
+    group_chat
+        .get_switchboard()
+        .add_event_handler_closure(move |event| {
+            let left = handler_left.clone();
+            async move {
+                if let msnp11_sdk::enums::event::Event::ParticipantLeftSwitchboard { email } = event
+                {
+                    left.lock().unwrap().push(email);
+                }
+            }
+        });
+
+    let message = msnp11_sdk::PlainText {
+        color: "ff".to_string(),
+        text: "h".to_string(),
+        ..Default::default()
+    };
+
+    // Erin leaves after the message
+    group_chat.send_text_message(&message).await.unwrap();
+    tokio::time::sleep(Duration::from_millis(100)).await;
+
+    assert_eq!(*one_to_one.lock().unwrap(), vec!["alice@passport.com"]);
+    assert_eq!(*copies.lock().unwrap(), 1);
+    assert_eq!(*left.lock().unwrap(), vec!["erin@passport.com"]);
+    assert_eq!(group_chat.get_members().await, vec!["alice@passport.com"]);
+    assert!(!group_chat.is_group().await);
+
+    group_chat.leave().await.unwrap();
+    client.disconnect().await.unwrap();
+}
+
+#[tokio::test]
+async fn convert_conversation_to_group() {
+    let client = login().await;
+    let conversation = client.get_conversation(&["bob@passport.com"]).await;
+
+    let group_chat = conversation.convert_to_group().await.unwrap();
+    assert_eq!(group_chat.get_members().await, vec!["bob@passport.com"]);
+    assert!(!group_chat.is_group().await);
+
+    // The session now belongs to the group chat
+    assert!(conversation.get_switchboard().await.is_none());
+
+    let results = group_chat
+        .invite(&["Alice@passport.com", "frank@passport.com"])
+        .await;
+
+    assert_eq!(results[0].status, msnp11_sdk::InviteStatus::Joined);
+    assert_eq!(results[1].status, msnp11_sdk::InviteStatus::Declined);
+    // Either the invite or the join event adds Alice first
+    let members: Vec<String> = group_chat
+        .get_members()
+        .await
+        .iter()
+        .map(|member| member.to_lowercase())
+        .collect();
+
+    assert_eq!(members, vec!["bob@passport.com", "alice@passport.com"]);
+
+    assert!(group_chat.is_group().await);
+
+    group_chat.leave().await.unwrap();
+    client.disconnect().await.unwrap();
+}
@@ -0,0 +1,79 @@
+#[tokio::test]
+async fn hotmail() {
+    let events = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+    let client = msnp11_sdk::client::Client::new("127.0.0.1", 1863)
+        .await
+        .unwrap();
+
+    let result = client
+        .login(
+            "testing@example.com".to_string(),
+            "123456",
+            "http://localhost:3000/rdr/pprdr.asp",
+            "msnp11-sdk",
+            "0.6",
+        )
+        .await
+        .unwrap();
+
+    assert!(matches!(
+        result,
+        msnp11_sdk::enums::event::Event::Authenticated
+    ));
+
+    let handler_events = events.clone();
+    client.add_event_handler_closure(move |event| {
+        let events = handler_events.clone();
+        async move {
+            events.lock().unwrap().push(event);
+        }
+    });
+
+    client
+        .set_presence(msnp11_sdk::enums::msnp_status::MsnpStatus::Online)
+        .await
+        .unwrap();
+
+    tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+    client.disconnect().await.unwrap();
+
+    let events = events.lock().unwrap();
+    assert!(events.iter().any(|event| matches!(
+        event,
+        msnp11_sdk::enums::event::Event::Profile(profile) if *profile == msnp11_sdk::Profile {
+            country: "US".to_string(),
+            language: 1033,
+            kid: false,
+            email_enabled: true,
+            mspauth: "7RgobFm*Tb7nEdDemo$$".to_string(),
+            client_ip: "127.0.0.1".to_string(),
+            client_port: 1849,
+        }
+    )));
+
+    assert!(events.iter().any(|event| matches!(
+        event,
+        msnp11_sdk::enums::event::Event::InboxStatus {
+            unread: 2,
+            folders_unread: 1
+        }
+    )));
+
+    assert!(events.iter().any(|event| matches!(
+        event,
+        msnp11_sdk::enums::event::Event::NewEmail { from, from_address, subject, folder }
+            if from == "Hotmail Staff"
+                && from_address == "staff@hotmail.com"
+                && subject == "Welcome to Hotmail"
+                && folder == "ACTIVE"
+    )));
+
+    assert!(events.iter().any(|event| matches!(
+        event,
+        msnp11_sdk::enums::event::Event::MailboxActivity {
+            source_folder,
+            destination_folder,
+            message_delta: 1
+        } if source_folder == "ACTIVE" && destination_folder == "trAsH"
+    )));
+}
@@ -0,0 +1,60 @@
+#[tokio::test]
+async fn login_through_gateway() {
+    let transport = msnp11_sdk::enums::transport::Transport::HttpGateway {
+        gateway_url: "http://127.0.0.1:3001/gateway/gateway.dll".to_string(),
+    };
+
+    let mut client =
+        msnp11_sdk::client::Client::new_with_transport("127.0.0.1", 1863, transport.clone())
+            .await
+            .unwrap();
+
+    let result: msnp11_sdk::enums::event::Event = match client
+        .login(
+            "testing@example.com".to_string(),
+            "123456",
+            "http://localhost:3000/rdr/pprdr.asp",
+            "msnp11-sdk",
+            "0.6",
+        )
+        .await
+    {
+        Ok(msnp11_sdk::enums::event::Event::RedirectedTo { server, port }) => {
+            client = msnp11_sdk::client::Client::new_with_transport(&server, port, transport)
+                .await
+                .unwrap();
+
+            client
+                .login(
+                    "testing@example.com".to_string(),
+                    "123456",
+                    "http://localhost:3000/rdr/pprdr.asp",
+                    "msnp11-sdk",
+                    "0.6",
+                )
+                .await
+                .unwrap()
+        }
+
+        Ok(msnp11_sdk::enums::event::Event::Authenticated) => {
+            msnp11_sdk::enums::event::Event::Authenticated
+        }
+        Err(err) => panic!("Login error: {err}"),
+        _ => msnp11_sdk::enums::event::Event::Disconnected,
+    };
+
+    assert!(matches!(
+        result,
+        msnp11_sdk::enums::event::Event::Authenticated
+    ));
+
+    client
+        .set_presence(msnp11_sdk::enums::msnp_status::MsnpStatus::Online)
+        .await
+        .unwrap();
+
+    client.disconnect().await.unwrap();
+
+    // Give the gateway task time to close the session
+    tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+}
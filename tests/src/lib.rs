@@ -1,4 +1,43 @@
+#![cfg(test)]
+
+// Some of the older tests are kept as written, predating these lints
+#[allow(
+    clippy::explicit_auto_deref,
+    clippy::single_match,
+    clippy::unnecessary_to_owned
+)]
 mod add_contact;
+mod alerts;
+mod authenticators;
+mod capabilities;
+mod challenge;
+mod chunking;
+#[allow(clippy::unnecessary_to_owned)]
 mod config;
+mod contact_requests;
+mod conversations;
+mod current_media;
+mod delivery;
+mod emoticons;
+mod formatting;
+mod group_chat;
+mod hotmail;
+mod http_gateway;
+#[allow(clippy::unnecessary_to_owned)]
 mod login;
+mod markup;
+#[allow(
+    clippy::explicit_auto_deref,
+    clippy::single_match,
+    clippy::unnecessary_to_owned
+)]
 mod messaging;
+mod phone;
+mod presence_manager;
+mod privacy;
+mod protocol_log;
+mod proxy;
+mod raw_messages;
+mod roster;
+mod service_url;
+mod typing;
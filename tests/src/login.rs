@@ -38,15 +38,19 @@ async fn login() {
     client
         .set_personal_message(&msnp11_sdk::models::personal_message::PersonalMessage {
             psm: "test".to_string(),
-            current_media: "".to_string(),
+            current_media: None,
         })
         .await
         .unwrap();
 
     client.add_event_handler_closure(|event| async {
         match event {
-            msnp11_sdk::enums::event::Event::Gtc(gtc) => assert_eq!(gtc, "A"),
-            msnp11_sdk::enums::event::Event::Blp(blp) => assert_eq!(blp, "AL"),
+            msnp11_sdk::enums::event::Event::Gtc(gtc) => {
+                assert_eq!(gtc, msnp11_sdk::AddNotificationMode::Prompt)
+            }
+            msnp11_sdk::enums::event::Event::Blp(blp) => {
+                assert_eq!(blp, msnp11_sdk::PrivacyMode::AllowUnlisted)
+            }
             msnp11_sdk::enums::event::Event::DisplayName(display_name) => {
                 assert_eq!(display_name, "Testing")
             }
@@ -100,6 +104,7 @@ async fn login() {
                 assert_eq!(presence.msn_object.as_ref().unwrap().creator, email);
                 assert_eq!(presence.msn_object.as_ref().unwrap().size, 22731);
                 assert_eq!(presence.msn_object.as_ref().unwrap().object_type, 3);
+                assert_eq!(presence.client_id.msnc_version(), 4);
             }
 
             msnp11_sdk::enums::event::Event::PersonalMessageUpdate {
@@ -111,7 +116,17 @@ async fn login() {
                     personal_message,
                     msnp11_sdk::models::personal_message::PersonalMessage {
                         psm: "my msn all ducked".to_string(),
-                        current_media: "".to_string()
+                        current_media: Some(msnp11_sdk::CurrentMedia {
+                            app: "".to_string(),
+                            media_type: msnp11_sdk::CurrentMediaType::Music,
+                            enabled: true,
+                            format: "{0} - {1}".to_string(),
+                            fields: vec![
+                                "Rock & Roll".to_string(),
+                                "Band".to_string(),
+                                "".to_string()
+                            ],
+                        })
                     }
                 );
             }
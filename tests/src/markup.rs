@@ -0,0 +1,88 @@
+use msnp11_sdk::models::markup::{
+    plain_text_from_html, plain_text_from_markdown, plain_text_to_html, plain_text_to_markdown,
+};
+
+fn message() -> msnp11_sdk::PlainText {
+    msnp11_sdk::PlainText {
+        bold: true,
+        italic: false,
+        underline: true,
+        strikethrough: false,
+        color: "ff8000".to_string(),
+        font_name: "Segoe UI".to_string(),
+        text: "<script>1 & 2</script>\nnext".to_string(),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn html_round_trip() {
+    let html = plain_text_to_html(&message());
+    assert_eq!(
+        html,
+        "<span style=\"font-family: 'Segoe UI'; color: #ff8000\"><b><u>&lt;script&gt;1 &amp; 2&lt;/script&gt;<br>next</u></b></span>"
+    );
+
+    let parsed = plain_text_from_html(&html);
+    assert!(parsed.bold);
+    assert!(parsed.underline);
+    assert!(!parsed.italic);
+    assert_eq!(parsed.color, "ff8000");
+    assert_eq!(parsed.text, message().text);
+}
+
+#[test]
+fn html_subset() {
+    let parsed = plain_text_from_html(
+        "<p><font color=\"#00F\"><em>Hi</em> <a href=\"x\">there</a>&#33;</font></p><img src=\"x\">",
+    );
+
+    assert!(parsed.italic);
+    assert!(!parsed.bold);
+    assert_eq!(parsed.color, "ff");
+    assert_eq!(parsed.text, "Hi there!");
+
+    let parsed =
+        plain_text_from_html("<span style=\"background-color: #ff0000; color: #0000ff\">Hi</span>");
+
+    assert_eq!(parsed.color, "ff");
+
+    let parsed = plain_text_from_html("<span style=\"background-color: #ff0000\">Hi</span>");
+    assert_eq!(parsed.color, "0");
+}
+
+#[test]
+fn markdown_round_trip() {
+    let mut message = message();
+    message.text = "2 * 3 = *6*\n\nsnake_case".to_string();
+
+    let markdown = plain_text_to_markdown(&message);
+    assert_eq!(
+        markdown,
+        "**<u>2 \\* 3 = \\*6\\*</u>**\n\n**<u>snake\\_case</u>**"
+    );
+
+    let parsed = plain_text_from_markdown(&markdown);
+    assert!(parsed.bold);
+    assert!(parsed.underline);
+    assert!(!parsed.italic);
+    assert_eq!(parsed.text, message.text);
+}
+
+#[test]
+fn markdown_subset() {
+    let parsed = plain_text_from_markdown("_hello_ ~~world~~ snake_case 2 * 3");
+    assert!(parsed.italic);
+    assert!(parsed.strikethrough);
+    assert!(!parsed.bold);
+    assert_eq!(parsed.text, "hello world snake_case 2 * 3");
+
+    let parsed = plain_text_from_markdown("2 * 3 * 4");
+    assert!(!parsed.italic);
+    assert_eq!(parsed.text, "2 * 3 * 4");
+
+    let parsed = plain_text_from_markdown("**bold** and *italic*");
+    assert!(parsed.bold);
+    assert!(parsed.italic);
+    assert_eq!(parsed.text, "bold and italic");
+}
@@ -50,6 +50,7 @@ async fn create_session() {
         strikethrough: false,
         color: "ff".to_string(),
         text: "h".to_string(),
+        ..Default::default()
     };
 
     let switchboard = client
@@ -60,7 +61,7 @@ async fn create_session() {
     switchboard.send_text_message(&message).await.unwrap();
     switchboard.add_event_handler_closure(|event| async {
         match event {
-            msnp11_sdk::enums::event::Event::ParticipantInSwitchboard { email } => {
+            msnp11_sdk::enums::event::Event::ParticipantInSwitchboard { email, .. } => {
                 assert_eq!(email, "bob@passport.com");
             }
 
@@ -132,13 +133,18 @@ async fn join_session() {
     ));
 
     // GTC abuse from the mock server
-    client.set_gtc(&"ReceiveRNG".to_string()).await.unwrap();
+    client
+        .set_gtc(msnp11_sdk::AddNotificationMode::DontPrompt)
+        .await
+        .unwrap();
     client.add_event_handler_closure(|event| async {
         match event {
             msnp11_sdk::enums::event::Event::SessionAnswered(switchboard) => {
                 switchboard.add_event_handler_closure(|event| async {
                     match event {
-                        msnp11_sdk::enums::event::Event::ParticipantInSwitchboard { email } => {
+                        msnp11_sdk::enums::event::Event::ParticipantInSwitchboard {
+                            email, ..
+                        } => {
                             assert_eq!(email, "bob@passport.com");
                         }
 
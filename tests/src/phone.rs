@@ -0,0 +1,92 @@
+use std::sync::{Arc, Mutex};
+
+#[tokio::test]
+async fn phone_properties_and_pages() {
+    let client = msnp11_sdk::client::Client::new("127.0.0.1", 1863)
+        .await
+        .unwrap();
+
+    let result = client
+        .login(
+            "testing@example.com".to_string(),
+            "123456",
+            "http://localhost:3000/rdr/pprdr.asp",
+            "msnp11-sdk",
+            "0.6",
+        )
+        .await
+        .unwrap();
+
+    assert!(matches!(
+        result,
+        msnp11_sdk::enums::event::Event::Authenticated
+    ));
+
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let handler_events = events.clone();
+    client.add_event_handler_closure(move |event| {
+        let events = handler_events.clone();
+        async move {
+            match event {
+                msnp11_sdk::enums::event::Event::UserPhoneProperty(_)
+                | msnp11_sdk::enums::event::Event::ContactPhoneProperty { .. } => {
+                    events.lock().unwrap().push(event)
+                }
+
+                _ => (),
+            }
+        }
+    });
+
+    client
+        .set_phone_property(msnp11_sdk::PhoneProperty::HomePhone("555 555".to_string()))
+        .await
+        .unwrap();
+
+    client
+        .send_page("bob@passport.com", "call me & text")
+        .await
+        .unwrap();
+
+    let events = events.lock().unwrap().clone();
+    assert!(events.iter().any(|event| matches!(
+        event,
+        msnp11_sdk::enums::event::Event::UserPhoneProperty(msnp11_sdk::PhoneProperty::HomePhone(number))
+            if number == "555 123"
+    )));
+
+    assert!(events.iter().any(|event| matches!(
+        event,
+        msnp11_sdk::enums::event::Event::ContactPhoneProperty {
+            email,
+            property: msnp11_sdk::PhoneProperty::MobilePhone(number),
+        } if email == "bob@passport.com" && number == "555 987"
+    )));
+
+    assert!(events.iter().any(|event| matches!(
+        event,
+        msnp11_sdk::enums::event::Event::ContactPhoneProperty {
+            email,
+            property: msnp11_sdk::PhoneProperty::MobilePaging(true),
+        } if email == "bob@passport.com"
+    )));
+
+    // Live changes name the contact, even without a value
+    assert!(events.iter().any(|event| matches!(
+        event,
+        msnp11_sdk::enums::event::Event::ContactPhoneProperty {
+            email,
+            property: msnp11_sdk::PhoneProperty::WorkPhone(number),
+        } if email == "fred@passport.com" && number == "555 111"
+    )));
+
+    assert!(events.iter().any(|event| matches!(
+        event,
+        msnp11_sdk::enums::event::Event::ContactPhoneProperty {
+            email,
+            property: msnp11_sdk::PhoneProperty::MobilePhone(number),
+        } if email == "fred@passport.com" && number.is_empty()
+    )));
+
+    client.disconnect().await.unwrap();
+}
@@ -0,0 +1,123 @@
+use std::time::Duration;
+
+async fn login() -> msnp11_sdk::Client {
+    let client = msnp11_sdk::client::Client::new("127.0.0.1", 1863)
+        .await
+        .unwrap();
+
+    let result = client
+        .login(
+            "testing@example.com".to_string(),
+            "123456",
+            "http://localhost:3000/rdr/pprdr.asp",
+            "msnp11-sdk",
+            "0.6",
+        )
+        .await
+        .unwrap();
+
+    assert!(matches!(
+        result,
+        msnp11_sdk::enums::event::Event::Authenticated
+    ));
+
+    client
+}
+
+#[tokio::test]
+async fn auto_away() {
+    let presence_manager = msnp11_sdk::PresenceManager::new(msnp11_sdk::AutoAwaySettings {
+        idle_after: Duration::from_millis(200),
+        away_after: Some(Duration::from_millis(400)),
+        away_message: Some(msnp11_sdk::PersonalMessage {
+            psm: "brb".to_string(),
+            current_media: None,
+        }),
+    });
+
+    let client = login().await;
+    client
+        .attach_presence_manager(&presence_manager)
+        .await
+        .unwrap();
+
+    client
+        .set_presence(msnp11_sdk::MsnpStatus::Online)
+        .await
+        .unwrap();
+
+    tokio::time::sleep(Duration::from_millis(300)).await;
+    assert_eq!(
+        presence_manager.get_auto_status().await,
+        Some(msnp11_sdk::MsnpStatus::Idle)
+    );
+
+    tokio::time::sleep(Duration::from_millis(200)).await;
+    assert_eq!(
+        presence_manager.get_auto_status().await,
+        Some(msnp11_sdk::MsnpStatus::Away)
+    );
+
+    presence_manager.report_activity().await.unwrap();
+    assert_eq!(presence_manager.get_auto_status().await, None);
+    client.disconnect().await.unwrap();
+
+    // The status is set again on the new connection
+    let client = login().await;
+    tokio::time::timeout(
+        Duration::from_secs(1),
+        client.attach_presence_manager(&presence_manager),
+    )
+    .await
+    .unwrap()
+    .unwrap();
+
+    assert_eq!(
+        presence_manager.get_status().await,
+        Some(msnp11_sdk::MsnpStatus::Online)
+    );
+
+    client.detach_presence_manager().await;
+    client.disconnect().await.unwrap();
+}
+
+#[tokio::test]
+async fn attach_after_setting_presence() {
+    let presence_manager = msnp11_sdk::PresenceManager::new(msnp11_sdk::AutoAwaySettings {
+        idle_after: Duration::from_millis(200),
+        away_after: None,
+        away_message: None,
+    });
+
+    let client = login().await;
+    client
+        .set_presence(msnp11_sdk::MsnpStatus::Online)
+        .await
+        .unwrap();
+
+    client
+        .attach_presence_manager(&presence_manager)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        presence_manager.get_status().await,
+        Some(msnp11_sdk::MsnpStatus::Online)
+    );
+
+    tokio::time::sleep(Duration::from_millis(300)).await;
+    assert_eq!(
+        presence_manager.get_auto_status().await,
+        Some(msnp11_sdk::MsnpStatus::Idle)
+    );
+
+    // The mock server refuses the change back, so the user is still idle
+    assert!(presence_manager.report_activity().await.is_err());
+    assert_eq!(
+        presence_manager.get_auto_status().await,
+        Some(msnp11_sdk::MsnpStatus::Idle)
+    );
+
+    client.detach_presence_manager().await;
+    client.disconnect().await.unwrap();
+}
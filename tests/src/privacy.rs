@@ -0,0 +1,68 @@
+#[tokio::test]
+async fn privacy() {
+    let client = msnp11_sdk::client::Client::new("127.0.0.1", 1863)
+        .await
+        .unwrap();
+
+    let result = client
+        .login(
+            "testing@example.com".to_string(),
+            "123456",
+            "http://localhost:3000/rdr/pprdr.asp",
+            "msnp11-sdk",
+            "0.6",
+        )
+        .await
+        .unwrap();
+
+    assert!(matches!(
+        result,
+        msnp11_sdk::enums::event::Event::Authenticated
+    ));
+
+    assert_eq!(
+        client.get_privacy_mode().await,
+        Some(msnp11_sdk::PrivacyMode::AllowUnlisted)
+    );
+
+    assert!(!client.is_contact_allowed("bob@passport.com").await);
+    assert!(client.is_contact_allowed("fred@passport.com").await);
+    assert!(client.is_contact_allowed("someone@passport.com").await);
+
+    // Rejected before sending anything
+    assert!(matches!(
+        client.block_contact("bob@passport.com").await,
+        Err(msnp11_sdk::ContactError::AlreadyInList)
+    ));
+
+    client.unblock_contact("bob@passport.com").await.unwrap();
+    assert!(client.is_contact_allowed("bob@passport.com").await);
+
+    // Already set, so no command is sent
+    client
+        .set_blp(msnp11_sdk::PrivacyMode::AllowUnlisted)
+        .await
+        .unwrap();
+
+    client
+        .set_blp(msnp11_sdk::PrivacyMode::BlockUnlisted)
+        .await
+        .unwrap();
+
+    assert!(!client.is_contact_allowed("someone@passport.com").await);
+    assert!(client.is_contact_allowed("bob@passport.com").await);
+
+    // The mock server fails to block Fred, who is allowed again
+    assert!(matches!(
+        client.block_contact("fred@passport.com").await,
+        Err(msnp11_sdk::ContactError::ServerError)
+    ));
+
+    assert!(client.is_contact_allowed("fred@passport.com").await);
+    assert!(matches!(
+        client.unblock_contact("fred@passport.com").await,
+        Err(msnp11_sdk::ContactError::AlreadyInList)
+    ));
+
+    client.disconnect().await.unwrap();
+}
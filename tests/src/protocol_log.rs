@@ -0,0 +1,126 @@
+#[tokio::test]
+async fn protocol_log() {
+    let entries = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+    let client = msnp11_sdk::client::Client::new("127.0.0.1", 1863)
+        .await
+        .unwrap();
+
+    let handler_entries = entries.clone();
+    client.set_protocol_log_handler_closure(move |entry| {
+        handler_entries.lock().unwrap().push(entry);
+    });
+
+    let result = client
+        .login(
+            "testing@example.com".to_string(),
+            "123456",
+            "http://localhost:3000/rdr/pprdr.asp",
+            "msnp11-sdk",
+            "0.6",
+        )
+        .await
+        .unwrap();
+
+    assert!(matches!(
+        result,
+        msnp11_sdk::enums::event::Event::Authenticated
+    ));
+
+    let switchboard = client.create_session("bob@passport.com").await.unwrap();
+    switchboard.disconnect().await.unwrap();
+    client.disconnect().await.unwrap();
+
+    let entries = entries.lock().unwrap();
+    assert!(
+        entries
+            .iter()
+            .all(|entry| !entry.command.contains("aaa123aaa123")
+                && !entry.command.contains("ct=1")
+                && !entry.command.contains("123456"))
+    );
+
+    assert!(entries.contains(&msnp11_sdk::ProtocolLogEntry {
+        direction: msnp11_sdk::ProtocolDirection::Sent,
+        server_type: msnp11_sdk::ServerType::NotificationServer,
+        command: "USR 4 TWN S [redacted]".to_string(),
+        payload_length: 0,
+    }));
+
+    assert!(entries.contains(&msnp11_sdk::ProtocolLogEntry {
+        direction: msnp11_sdk::ProtocolDirection::Received,
+        server_type: msnp11_sdk::ServerType::NotificationServer,
+        command: "XFR 7 SB 127.0.0.1:1864 CKI [redacted]".to_string(),
+        payload_length: 0,
+    }));
+
+    assert!(entries.contains(&msnp11_sdk::ProtocolLogEntry {
+        direction: msnp11_sdk::ProtocolDirection::Received,
+        server_type: msnp11_sdk::ServerType::NotificationServer,
+        command: "GCF 6 Shields.xml 33".to_string(),
+        payload_length: 33,
+    }));
+
+    assert!(entries.contains(&msnp11_sdk::ProtocolLogEntry {
+        direction: msnp11_sdk::ProtocolDirection::Sent,
+        server_type: msnp11_sdk::ServerType::Switchboard,
+        command: "USR 1 testing@example.com [redacted]".to_string(),
+        payload_length: 0,
+    }));
+}
+
+static TRACES: std::sync::Mutex<Vec<String>> = std::sync::Mutex::new(Vec::new());
+
+struct TraceCapture;
+
+impl log::Log for TraceCapture {
+    fn enabled(&self, _: &log::Metadata) -> bool {
+        true
+    }
+
+    fn log(&self, record: &log::Record) {
+        TRACES.lock().unwrap().push(record.args().to_string());
+    }
+
+    fn flush(&self) {}
+}
+
+static TRACE_CAPTURE: TraceCapture = TraceCapture;
+
+#[tokio::test]
+async fn trace_redaction() {
+    log::set_logger(&TRACE_CAPTURE).unwrap();
+    log::set_max_level(log::LevelFilter::Trace);
+
+    let client = msnp11_sdk::client::Client::new("127.0.0.1", 1863)
+        .await
+        .unwrap();
+
+    let result = client
+        .login(
+            "testing@example.com".to_string(),
+            "123456",
+            "http://localhost:3000/rdr/pprdr.asp",
+            "msnp11-sdk",
+            "0.6",
+        )
+        .await
+        .unwrap();
+
+    assert!(matches!(
+        result,
+        msnp11_sdk::enums::event::Event::Authenticated
+    ));
+
+    client.disconnect().await.unwrap();
+
+    let traces = TRACES.lock().unwrap();
+    assert!(
+        traces
+            .iter()
+            .any(|trace| trace == "S: MSG Hotmail Hotmail 402 [402 byte payload]")
+    );
+
+    assert!(traces.iter().all(|trace| !trace.contains("7RgobFm")
+        && !trace.contains("aaa123aaa123")
+        && !trace.contains("ct=1")));
+}
@@ -0,0 +1,211 @@
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU32, Ordering};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+
+/// Minimal SOCKS5 and HTTP proxy that requires the `user:pass` credentials and counts tunnels.
+async fn start_proxy(
+    proxy_type: msnp11_sdk::enums::proxy_type::ProxyType,
+) -> (u16, Arc<AtomicU32>) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let port = listener.local_addr().unwrap().port();
+    let connections = Arc::new(AtomicU32::new(0));
+
+    let counter = connections.clone();
+    tokio::spawn(async move {
+        while let Ok((socket, _)) = listener.accept().await {
+            let counter = counter.clone();
+            let proxy_type = proxy_type.clone();
+            tokio::spawn(async move {
+                let upstream = match proxy_type {
+                    msnp11_sdk::enums::proxy_type::ProxyType::Socks5 => socks5(socket).await,
+                    msnp11_sdk::enums::proxy_type::ProxyType::HttpConnect => http(socket).await,
+                };
+
+                if let Some((mut socket, mut upstream)) = upstream {
+                    counter.fetch_add(1, Ordering::SeqCst);
+                    let _ = tokio::io::copy_bidirectional(&mut socket, &mut upstream).await;
+                }
+            });
+        }
+    });
+
+    (port, connections)
+}
+
+async fn socks5(mut socket: TcpStream) -> Option<(TcpStream, TcpStream)> {
+    let mut greeting = [0; 2];
+    socket.read_exact(&mut greeting).await.ok()?;
+    let mut methods = vec![0; greeting[1] as usize];
+    socket.read_exact(&mut methods).await.ok()?;
+
+    if !methods.contains(&2) {
+        socket.write_all(&[5, 0xff]).await.ok()?;
+        return None;
+    }
+
+    socket.write_all(&[5, 2]).await.ok()?;
+
+    let mut credentials = Vec::new();
+    let mut header = [0; 2];
+    socket.read_exact(&mut header).await.ok()?;
+    let mut username = vec![0; header[1] as usize];
+    socket.read_exact(&mut username).await.ok()?;
+    let password_len = socket.read_u8().await.ok()?;
+    let mut password = vec![0; password_len as usize];
+    socket.read_exact(&mut password).await.ok()?;
+    credentials.extend_from_slice(&username);
+    credentials.push(b':');
+    credentials.extend_from_slice(&password);
+
+    if credentials != b"user:pass" {
+        socket.write_all(&[1, 1]).await.ok()?;
+        return None;
+    }
+
+    socket.write_all(&[1, 0]).await.ok()?;
+
+    let mut request = [0; 4];
+    socket.read_exact(&mut request).await.ok()?;
+    let address = match request[3] {
+        1 => {
+            let mut ip = [0; 4];
+            socket.read_exact(&mut ip).await.ok()?;
+            std::net::Ipv4Addr::from(ip).to_string()
+        }
+
+        3 => {
+            let len = socket.read_u8().await.ok()?;
+            let mut domain = vec![0; len as usize];
+            socket.read_exact(&mut domain).await.ok()?;
+            String::from_utf8(domain).ok()?
+        }
+
+        _ => return None,
+    };
+
+    let port = socket.read_u16().await.ok()?;
+    let upstream = TcpStream::connect((address.as_str(), port)).await.ok()?;
+
+    socket
+        .write_all(&[5, 0, 0, 1, 127, 0, 0, 1, 0, 0])
+        .await
+        .ok()?;
+
+    Some((socket, upstream))
+}
+
+async fn http(mut socket: TcpStream) -> Option<(TcpStream, TcpStream)> {
+    let mut request = Vec::new();
+    while !request.ends_with(b"\r\n\r\n") {
+        request.push(socket.read_u8().await.ok()?);
+    }
+
+    let request_str = String::from_utf8_lossy(&request).to_string();
+    if !request_str.contains("Proxy-Authorization: Basic dXNlcjpwYXNz")
+        && !request_str.contains("proxy-authorization: Basic dXNlcjpwYXNz")
+    {
+        socket
+            .write_all(b"HTTP/1.1 407 Proxy Authentication Required\r\n\r\n")
+            .await
+            .ok()?;
+
+        return None;
+    }
+
+    let mut request_line = request_str.split_ascii_whitespace();
+    let method = request_line.next()?;
+    let target = request_line.next()?;
+
+    if method == "CONNECT" {
+        let upstream = TcpStream::connect(target).await.ok()?;
+        socket
+            .write_all(b"HTTP/1.1 200 Connection established\r\n\r\n")
+            .await
+            .ok()?;
+
+        Some((socket, upstream))
+    } else {
+        // Plain HTTP requests are forwarded to the host in the absolute URL
+        let host = target.strip_prefix("http://")?.split('/').next()?;
+        let mut upstream = TcpStream::connect(host).await.ok()?;
+        upstream.write_all(&request).await.ok()?;
+        Some((socket, upstream))
+    }
+}
+
+async fn login_through_proxy(proxy_type: msnp11_sdk::enums::proxy_type::ProxyType) {
+    let (proxy_port, connections) = start_proxy(proxy_type.clone()).await;
+    let proxy = msnp11_sdk::models::proxy::Proxy {
+        proxy_type,
+        server: "127.0.0.1".to_string(),
+        port: proxy_port,
+        username: Some("user".to_string()),
+        password: Some("pass".to_string()),
+    };
+
+    let transport = msnp11_sdk::enums::transport::Transport::Tcp;
+    let mut client = msnp11_sdk::client::Client::new_with_proxy(
+        "127.0.0.1",
+        1863,
+        transport.clone(),
+        proxy.clone(),
+    )
+    .await
+    .unwrap();
+
+    let result: msnp11_sdk::enums::event::Event = match client
+        .login(
+            "testing@example.com".to_string(),
+            "123456",
+            "http://localhost:3000/rdr/pprdr.asp",
+            "msnp11-sdk",
+            "0.6",
+        )
+        .await
+    {
+        Ok(msnp11_sdk::enums::event::Event::RedirectedTo { server, port }) => {
+            client = msnp11_sdk::client::Client::new_with_proxy(&server, port, transport, proxy)
+                .await
+                .unwrap();
+
+            client
+                .login(
+                    "testing@example.com".to_string(),
+                    "123456",
+                    "http://localhost:3000/rdr/pprdr.asp",
+                    "msnp11-sdk",
+                    "0.6",
+                )
+                .await
+                .unwrap()
+        }
+
+        Ok(msnp11_sdk::enums::event::Event::Authenticated) => {
+            msnp11_sdk::enums::event::Event::Authenticated
+        }
+        Err(err) => panic!("Login error: {err}"),
+        _ => msnp11_sdk::enums::event::Event::Disconnected,
+    };
+
+    assert!(matches!(
+        result,
+        msnp11_sdk::enums::event::Event::Authenticated
+    ));
+
+    assert!(connections.load(Ordering::SeqCst) > 0);
+    client.disconnect().await.unwrap();
+
+    // Give the proxy time to close the tunnel
+    tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+}
+
+#[tokio::test]
+async fn login_through_socks5_proxy() {
+    login_through_proxy(msnp11_sdk::enums::proxy_type::ProxyType::Socks5).await;
+}
+
+#[tokio::test]
+async fn login_through_http_proxy() {
+    login_through_proxy(msnp11_sdk::enums::proxy_type::ProxyType::HttpConnect).await;
+}
@@ -0,0 +1,120 @@
+use msnp11_sdk::{AckMode, MessagingError, MimeHeader};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+#[tokio::test]
+async fn raw_messages() {
+    let client = msnp11_sdk::client::Client::new("127.0.0.1", 1863)
+        .await
+        .unwrap();
+
+    let result = client
+        .login(
+            "testing@example.com".to_string(),
+            "123456",
+            "http://localhost:3000/rdr/pprdr.asp",
+            "msnp11-sdk",
+            "0.6",
+        )
+        .await
+        .unwrap();
+
+    assert!(matches!(
+        result,
+        msnp11_sdk::enums::event::Event::Authenticated
+    ));
+
+    let switchboard = client.create_session("bob@passport.com").await.unwrap();
+
+    let received = Arc::new(Mutex::new(Vec::new()));
+    let handler_received = received.clone();
+    switchboard.add_event_handler_closure(move |event| {
+        let received = handler_received.clone();
+        async move {
+            if let msnp11_sdk::enums::event::Event::RawMessage {
+                email,
+                content_type,
+                headers,
+                body,
+            } = event
+            {
+                received
+                    .lock()
+                    .unwrap()
+                    .push((email, content_type, headers, body));
+            }
+        }
+    });
+
+    let headers = vec![MimeHeader {
+        name: "Client-Name".to_string(),
+        value: "msnp11-sdk".to_string(),
+    }];
+
+    switchboard
+        .send_raw_message(
+            "text/x-clientcaps",
+            &headers,
+            b"hello",
+            AckMode::Acknowledged,
+        )
+        .await
+        .unwrap();
+
+    // Not acknowledged by the server
+    switchboard
+        .send_raw_message("text/x-keepalive", &[], b"", AckMode::Unacknowledged)
+        .await
+        .unwrap();
+
+    let injected = vec![MimeHeader {
+        name: "Client-Name".to_string(),
+        value: "a\r\nContent-Type: text/plain".to_string(),
+    }];
+
+    assert!(matches!(
+        switchboard
+            .send_raw_message("text/x-clientcaps", &injected, b"", AckMode::Negative)
+            .await,
+        Err(MessagingError::InvalidHeaders)
+    ));
+
+    assert!(matches!(
+        switchboard
+            .send_raw_message(
+                "application/octet-stream",
+                &[],
+                &[0xff, 0xfe],
+                AckMode::Negative
+            )
+            .await,
+        Err(MessagingError::InvalidBody)
+    ));
+
+    tokio::time::sleep(Duration::from_millis(100)).await;
+
+    let received = received.lock().unwrap().clone();
+    assert_eq!(received.len(), 1);
+
+    let (email, content_type, headers, body) = &received[0];
+    assert_eq!(email, "bob@passport.com");
+    assert_eq!(content_type, "text/x-clientcaps");
+    assert_eq!(
+        *headers,
+        vec![
+            MimeHeader {
+                name: "Client-Name".to_string(),
+                value: "Bob Messenger 1.0".to_string(),
+            },
+            MimeHeader {
+                name: "Chat-Logging".to_string(),
+                value: "Y".to_string(),
+            },
+        ]
+    );
+
+    assert!(body.is_empty());
+
+    switchboard.disconnect().await.unwrap();
+    client.disconnect().await.unwrap();
+}
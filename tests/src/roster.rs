@@ -0,0 +1,125 @@
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+async fn login() -> msnp11_sdk::Client {
+    let client = msnp11_sdk::client::Client::new("127.0.0.1", 1863)
+        .await
+        .unwrap();
+
+    let result = client
+        .login(
+            "testing@example.com".to_string(),
+            "123456",
+            "http://localhost:3000/rdr/pprdr.asp",
+            "msnp11-sdk",
+            "0.6",
+        )
+        .await
+        .unwrap();
+
+    assert!(matches!(
+        result,
+        msnp11_sdk::enums::event::Event::Authenticated
+    ));
+
+    client
+}
+
+#[tokio::test]
+async fn joined_participant() {
+    let client = login().await;
+    let switchboard = client.create_session("bob@passport.com").await.unwrap();
+    tokio::time::sleep(Duration::from_millis(100)).await;
+
+    let bob = msnp11_sdk::Participant {
+        email: "bob@passport.com".to_string(),
+        display_name: "Bob".to_string(),
+        capabilities: Some(msnp11_sdk::ClientCapabilities::default()),
+    };
+
+    assert_eq!(
+        switchboard.get_participant("bob@passport.com").await,
+        Some(bob.clone())
+    );
+
+    assert_eq!(switchboard.get_roster().await, vec![bob]);
+    assert!(
+        switchboard
+            .get_participant("alice@passport.com")
+            .await
+            .is_none()
+    );
+
+    switchboard.disconnect().await.unwrap();
+    client.disconnect().await.unwrap();
+}
+
+#[tokio::test]
+async fn answered_roster() {
+    let client = login().await;
+
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let handler_events = events.clone();
+    client.add_event_handler_closure(move |event| {
+        let events = handler_events.clone();
+        async move {
+            if let msnp11_sdk::enums::event::Event::SessionAnswered(switchboard) = event {
+                switchboard.add_event_handler_closure(move |event| {
+                    let events = events.clone();
+                    async move {
+                        match event {
+                            msnp11_sdk::enums::event::Event::ParticipantInSwitchboard {
+                                ..
+                            }
+                            | msnp11_sdk::enums::event::Event::ParticipantRosterComplete {
+                                ..
+                            } => events.lock().unwrap().push(event),
+
+                            _ => (),
+                        }
+                    }
+                });
+            }
+        }
+    });
+
+    // GTC abuse from the mock server
+    client
+        .set_gtc(msnp11_sdk::AddNotificationMode::DontPrompt)
+        .await
+        .unwrap();
+
+    tokio::time::sleep(Duration::from_millis(200)).await;
+
+    let events = events.lock().unwrap().clone();
+    assert_eq!(events.len(), 2);
+
+    let msnp11_sdk::enums::event::Event::ParticipantInSwitchboard {
+        email,
+        display_name,
+        capabilities,
+    } = &events[0]
+    else {
+        panic!("Expected a participant, got {:?}", events[0]);
+    };
+
+    assert_eq!(email, "bob@passport.com");
+    assert_eq!(display_name, "Bob Smith");
+    assert!(capabilities.is_none());
+
+    let msnp11_sdk::enums::event::Event::ParticipantRosterComplete { participants } = &events[1]
+    else {
+        panic!("Expected the roster, got {:?}", events[1]);
+    };
+
+    assert_eq!(
+        *participants,
+        vec![msnp11_sdk::Participant {
+            email: "bob@passport.com".to_string(),
+            display_name: "Bob Smith".to_string(),
+            capabilities: None,
+        }]
+    );
+
+    client.disconnect().await.unwrap();
+}
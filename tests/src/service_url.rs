@@ -0,0 +1,38 @@
+#[tokio::test]
+async fn request_service_url() {
+    let client = msnp11_sdk::client::Client::new("127.0.0.1", 1863)
+        .await
+        .unwrap();
+
+    let result = client
+        .login(
+            "testing@example.com".to_string(),
+            "123456",
+            "http://localhost:3000/rdr/pprdr.asp",
+            "msnp11-sdk",
+            "0.6",
+        )
+        .await
+        .unwrap();
+
+    assert!(matches!(
+        result,
+        msnp11_sdk::enums::event::Event::Authenticated
+    ));
+
+    let service_url = client
+        .request_service_url(msnp11_sdk::ServiceUrlKind::Inbox)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        service_url,
+        msnp11_sdk::ServiceUrl {
+            rru: "/cgi-bin/HoTMaiL".to_string(),
+            passport_url: "https://loginnet.passport.com/ppsecure/md5auth.srf?lc=1033".to_string(),
+            id: 2,
+        }
+    );
+
+    client.disconnect().await.unwrap();
+}
@@ -0,0 +1,92 @@
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+#[derive(Debug, PartialEq)]
+enum Typing {
+    Started(String),
+    Stopped(String),
+}
+
+#[tokio::test]
+async fn typing_lifecycle() {
+    let client = msnp11_sdk::client::Client::new("127.0.0.1", 1863)
+        .await
+        .unwrap();
+
+    let result = client
+        .login(
+            "testing@example.com".to_string(),
+            "123456",
+            "http://localhost:3000/rdr/pprdr.asp",
+            "msnp11-sdk",
+            "0.6",
+        )
+        .await
+        .unwrap();
+
+    assert!(matches!(
+        result,
+        msnp11_sdk::enums::event::Event::Authenticated
+    ));
+
+    let switchboard = client.create_session("bob@passport.com").await.unwrap();
+
+    let typing = Arc::new(Mutex::new(Vec::new()));
+    let handler_typing = typing.clone();
+    switchboard.add_event_handler_closure(move |event| {
+        let typing = handler_typing.clone();
+        async move {
+            match event {
+                msnp11_sdk::enums::event::Event::TypingStarted { email } => {
+                    typing.lock().unwrap().push(Typing::Started(email));
+                }
+
+                msnp11_sdk::enums::event::Event::TypingStopped { email } => {
+                    typing.lock().unwrap().push(Typing::Stopped(email));
+                }
+
+                _ => (),
+            }
+        }
+    });
+
+    // Bob types twice and sends a message
+    switchboard.notify_typing().await.unwrap();
+
+    // Too soon, nothing sent
+    switchboard.notify_typing().await.unwrap();
+    tokio::time::sleep(Duration::from_millis(100)).await;
+
+    assert_eq!(
+        *typing.lock().unwrap(),
+        vec![
+            Typing::Started("bob@passport.com".to_string()),
+            Typing::Stopped("bob@passport.com".to_string()),
+        ]
+    );
+
+    let message = msnp11_sdk::PlainText {
+        color: "ff".to_string(),
+        text: "h".to_string(),
+        ..Default::default()
+    };
+
+    // Sending a message lets the next keystroke notify right away, then Bob types once
+    switchboard.send_text_message(&message).await.unwrap();
+    switchboard.notify_typing().await.unwrap();
+    tokio::time::sleep(Duration::from_millis(100)).await;
+
+    assert_eq!(typing.lock().unwrap().len(), 3);
+
+    tokio::time::sleep(Duration::from_secs(6)).await;
+    assert_eq!(
+        typing.lock().unwrap()[2..],
+        [
+            Typing::Started("bob@passport.com".to_string()),
+            Typing::Stopped("bob@passport.com".to_string()),
+        ]
+    );
+
+    switchboard.disconnect().await.unwrap();
+    client.disconnect().await.unwrap();
+}